@@ -0,0 +1,38 @@
+use crate::wgpu;
+
+/// Resource that can be bound in a bind group, used by wrld::BindGroup to build the bind group entries from the fields.
+///
+/// Implemented by buffers (bound entirely), buffer bindings, texture views, samplers and references to them.
+pub trait AsBindingResource {
+    fn as_binding_resource(&self) -> wgpu::BindingResource<'_>;
+}
+
+impl AsBindingResource for wgpu::Buffer {
+    fn as_binding_resource(&self) -> wgpu::BindingResource<'_> {
+        self.as_entire_binding()
+    }
+}
+
+impl AsBindingResource for wgpu::BufferBinding<'_> {
+    fn as_binding_resource(&self) -> wgpu::BindingResource<'_> {
+        wgpu::BindingResource::Buffer(self.clone())
+    }
+}
+
+impl AsBindingResource for wgpu::TextureView {
+    fn as_binding_resource(&self) -> wgpu::BindingResource<'_> {
+        wgpu::BindingResource::TextureView(self)
+    }
+}
+
+impl AsBindingResource for wgpu::Sampler {
+    fn as_binding_resource(&self) -> wgpu::BindingResource<'_> {
+        wgpu::BindingResource::Sampler(self)
+    }
+}
+
+impl<T: AsBindingResource + ?Sized> AsBindingResource for &T {
+    fn as_binding_resource(&self) -> wgpu::BindingResource<'_> {
+        (**self).as_binding_resource()
+    }
+}
@@ -0,0 +1,212 @@
+use crate::wgpu;
+use crate::wgpu::naga;
+
+/// Scalar kind of a vertex format or of a shader input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarKind {
+    Float,
+    Uint,
+    Sint
+}
+
+impl ScalarKind {
+    /// Scalar kind read by the shader for a vertex format, normalized formats are read as float.
+    pub fn of_format(format: wgpu::VertexFormat) -> Self {
+        // Matched by name so that the check work with every supported wgpu version.
+        let name = format!("{:?}", format);
+
+        if name.starts_with("Uint") {
+            ScalarKind::Uint
+        } else if name.starts_with("Sint") {
+            ScalarKind::Sint
+        } else {
+            ScalarKind::Float
+        }
+    }
+}
+
+/// Shader input of the entry point without vertex attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingLocation {
+    pub location: u32,
+    pub name: Option<String>
+}
+
+/// Vertex attribute not read by the entry point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnusedAttribute {
+    /// Index of the layout in the given layouts.
+    pub buffer: usize,
+    pub location: u32,
+    pub format: wgpu::VertexFormat
+}
+
+/// Vertex attribute feeding a shader input of another scalar kind, like an integer format feeding a float input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KindMismatch {
+    /// Index of the layout in the given layouts.
+    pub buffer: usize,
+    pub location: u32,
+    pub format: wgpu::VertexFormat,
+    pub name: Option<String>,
+    /// Scalar kind of the shader input, `None` if it is not a number.
+    pub input: Option<ScalarKind>
+}
+
+/// Result of [`check_vertex_shader`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VertexShaderReport {
+    pub missing: Vec<MissingLocation>,
+    pub unused: Vec<UnusedAttribute>,
+    pub mismatches: Vec<KindMismatch>
+}
+
+impl VertexShaderReport {
+    /// The layouts can be used with the entry point.
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.unused.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+fn input_name(name: &Option<String>) -> &str {
+    name.as_deref().unwrap_or("<unnamed>")
+}
+
+impl std::fmt::Display for VertexShaderReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for missing in &self.missing {
+            writeln!(f, "shader input `{}` at location {} has no vertex attribute", input_name(&missing.name), missing.location)?;
+        }
+
+        for unused in &self.unused {
+            writeln!(f, "attribute {:?} at location {} of buffer {} is not read by the shader", unused.format, unused.location, unused.buffer)?;
+        }
+
+        for mismatch in &self.mismatches {
+            writeln!(
+                f,
+                "attribute {:?} at location {} of buffer {} cannot feed the shader input `{}` ({})",
+                mismatch.format,
+                mismatch.location,
+                mismatch.buffer,
+                input_name(&mismatch.name),
+                match mismatch.input {
+                    Some(kind) => format!("{:?}", kind),
+                    None => "not a number".to_string()
+                }
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The module has no vertex entry point with this name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPointNotFound(pub String);
+
+impl std::fmt::Display for EntryPointNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no vertex entry point `{}` in the shader module", self.0)
+    }
+}
+
+impl std::error::Error for EntryPointNotFound {}
+
+/// Check vertex buffer layouts against the vertex entry point of a naga module,
+/// for shaders that are only known at runtime. Run it before creating the render pipeline.
+///
+/// ## Example
+/// ```
+/// use wrld::wgpu::naga;
+///
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x3(0)] position: [f32; 3],
+///     #[u8x4(1)] color: [u8; 4]
+/// }
+///
+/// let module = naga::front::wgsl::parse_str("
+///     @vertex
+///     fn vs_main(@location(0) position: vec3<f32>, @location(1) color: vec4<f32>) -> @builtin(position) vec4<f32> {
+///         return vec4<f32>(position, color.a);
+///     }
+/// ").unwrap();
+///
+/// let report = wrld::check_vertex_shader(&[Vertex::desc()], &module, "vs_main").unwrap();
+///
+/// assert!(!report.is_ok());
+/// assert_eq!(report.mismatches[0].location, 1);
+/// ```
+pub fn check_vertex_shader(
+    layouts: &[wgpu::VertexBufferLayout],
+    module: &naga::Module,
+    entry_point: &str
+) -> Result<VertexShaderReport, EntryPointNotFound> {
+    let entry = module.entry_points.iter()
+        .find(|e| e.stage == naga::ShaderStage::Vertex && e.name == entry_point)
+        .ok_or_else(|| EntryPointNotFound(entry_point.to_string()))?;
+
+    // Shader locations read by the entry point, from its arguments and the members of its structure arguments.
+    let mut inputs: Vec<(Option<String>, u32, naga::Handle<naga::Type>)> = Vec::new();
+
+    for argument in &entry.function.arguments {
+        match (&argument.binding, &module.types[argument.ty].inner) {
+            (Some(naga::Binding::Location { location, .. }), _) => inputs.push((argument.name.clone(), *location, argument.ty)),
+            (None, naga::TypeInner::Struct { members, .. }) => {
+                for member in members {
+                    if let Some(naga::Binding::Location { location, .. }) = member.binding {
+                        inputs.push((member.name.clone(), location, member.ty));
+                    }
+                }
+            },
+            _ => ()
+        }
+    }
+
+    let attributes: Vec<(usize, &wgpu::VertexAttribute)> = layouts.iter().enumerate()
+        .flat_map(|(buffer, layout)| layout.attributes.iter().map(move |attribute| (buffer, attribute)))
+        .collect();
+
+    let mut report = VertexShaderReport::default();
+
+    for (name, location, ty) in &inputs {
+        let Some((buffer, attribute)) = attributes.iter().find(|(_, a)| a.shader_location == *location) else {
+            report.missing.push(MissingLocation { location: *location, name: name.clone() });
+            continue
+        };
+
+        let input = match module.types[*ty].inner {
+            naga::TypeInner::Scalar(scalar) | naga::TypeInner::Vector { scalar, .. } => match scalar.kind {
+                naga::ScalarKind::Float => Some(ScalarKind::Float),
+                naga::ScalarKind::Uint => Some(ScalarKind::Uint),
+                naga::ScalarKind::Sint => Some(ScalarKind::Sint),
+                _ => None
+            },
+            _ => None
+        };
+
+        if input != Some(ScalarKind::of_format(attribute.format)) {
+            report.mismatches.push(KindMismatch {
+                buffer: *buffer,
+                location: *location,
+                format: attribute.format,
+                name: name.clone(),
+                input
+            });
+        }
+    }
+
+    for (buffer, attribute) in &attributes {
+        if !inputs.iter().any(|(_, location, _)| *location == attribute.shader_location) {
+            report.unused.push(UnusedAttribute {
+                buffer: *buffer,
+                location: attribute.shader_location,
+                format: attribute.format
+            });
+        }
+    }
+
+    Ok(report)
+}
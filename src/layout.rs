@@ -0,0 +1,50 @@
+#[cfg(any(feature = "wgpu-0_19", feature = "wgpu-latest"))]
+use crate::wgpu;
+// Types shared with the wgpu 0.13 of the user, wrld cannot depend on wgpu 0.13 itself.
+#[cfg(not(any(feature = "wgpu-0_19", feature = "wgpu-latest")))]
+use wgpu_types_0_13 as wgpu;
+
+/// Vertex buffer layout of a structure, implemented by wrld::Desc and wrld::DescInstance.
+/// 
+/// ## Example
+/// ```
+/// use wrld::VertexLayout;
+/// 
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x2(0)] position: [f32; 2]
+/// }
+/// 
+/// #[repr(C)]
+/// #[derive(wrld::DescInstance)]
+/// struct Instance {
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// 
+/// fn buffers<V: VertexLayout, I: VertexLayout>() -> [wrld::wgpu::VertexBufferLayout<'static>; 2] {
+///     [V::LAYOUT, I::LAYOUT]
+/// }
+/// 
+/// let [vertex, instance] = buffers::<Vertex, Instance>();
+/// assert_eq!(vertex.step_mode, wrld::wgpu::VertexStepMode::Vertex);
+/// assert_eq!(instance.step_mode, wrld::wgpu::VertexStepMode::Instance);
+/// ```
+pub trait VertexLayout {
+    /// Attributes of the structure, one per shader location.
+    const ATTRIBUTES: &'static [wgpu::VertexAttribute];
+
+    /// Size of the structure in bytes.
+    const ARRAY_STRIDE: wgpu::BufferAddress;
+
+    /// Vertex for wrld::Desc, Instance for wrld::DescInstance.
+    const STEP_MODE: wgpu::VertexStepMode;
+
+    /// Layout to pass to the vertex state of a render pipeline, not available with wgpu 0.13.
+    #[cfg(any(feature = "wgpu-0_19", feature = "wgpu-latest"))]
+    const LAYOUT: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
+        array_stride: Self::ARRAY_STRIDE,
+        step_mode: Self::STEP_MODE,
+        attributes: Self::ATTRIBUTES
+    };
+}
@@ -0,0 +1,50 @@
+/// The destination of `write_packed` cannot hold the packed structures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// Size in bytes needed by the packed structures.
+    pub required: usize,
+    /// Size in bytes of the destination.
+    pub len: usize
+}
+
+impl std::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the destination is {} bytes but the packed data need {} bytes", self.len, self.required)
+    }
+}
+
+impl std::error::Error for BufferTooSmall {}
+
+/// The bytes given to `unpack_into` do not match the destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnpackError {
+    /// The length of the bytes is not a multiple of the size of the packed structure.
+    PartialRecord {
+        /// Size in bytes of the data.
+        len: usize,
+        /// Size in bytes of the packed structure.
+        stride: usize
+    },
+    /// The bytes do not hold one packed structure per destination structure.
+    CountMismatch {
+        /// Number of packed structures in the bytes.
+        records: usize,
+        /// Number of destination structures.
+        len: usize
+    }
+}
+
+impl std::fmt::Display for UnpackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnpackError::PartialRecord { len, stride } => write!(
+                f, "the data is {} bytes, which is not a multiple of the {} bytes of the packed structure", len, stride
+            ),
+            UnpackError::CountMismatch { records, len } => write!(
+                f, "the data hold {} packed structures but the destination has {} structures", records, len
+            )
+        }
+    }
+}
+
+impl std::error::Error for UnpackError {}
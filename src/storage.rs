@@ -0,0 +1,57 @@
+/// Structure of a storage buffer following the std430 layout of WGSL, implemented by wrld::Storage.
+///
+/// Structures implementing it can be used as fields and as runtime-sized array elements of other wrld::Storage structures.
+/// Structures ending with a runtime-sized array does not implement it, WGSL does not allow to nest them.
+///
+/// ## Example
+/// ```
+/// use wrld::Storage;
+///
+/// #[derive(wrld::Storage)]
+/// struct Particle {
+///     position: [f32; 3],
+///     life: f32,
+///     velocity: [f32; 3]
+/// }
+///
+/// assert_eq!(Particle::ALIGN, 16);
+/// assert_eq!(Particle::SIZE, 32);
+/// ```
+pub trait Storage {
+    /// Alignment of the structure in bytes.
+    const ALIGN: u64;
+
+    /// Size of the structure in bytes, a multiple of `ALIGN`.
+    const SIZE: u64;
+
+    /// Write the structure following the storage layout, `dst` is `SIZE` bytes long.
+    fn write_storage(&self, dst: &mut [u8]);
+}
+
+/// Round `value` up to a multiple of `align`.
+pub const fn round_up(align: u64, value: u64) -> u64 {
+    value.div_ceil(align) * align
+}
+
+/// Offsets, alignment and size of a structure from the `(align, size)` of its members.
+pub const fn struct_layout<const N: usize>(members: [(u64, u64); N]) -> ([u64; N], u64, u64) {
+    let mut offsets = [0; N];
+    let mut offset = 0;
+    let mut align = 1;
+    let mut i = 0;
+
+    while i < N {
+        let (member_align, member_size) = members[i];
+        offset = round_up(member_align, offset);
+        offsets[i] = offset;
+        offset += member_size;
+
+        if member_align > align {
+            align = member_align;
+        }
+
+        i += 1;
+    }
+
+    (offsets, align, round_up(align, offset))
+}
@@ -1,4 +1,6 @@
-use wrld::{Desc, DescInstance, BufferData};
+use wrld::{Desc, DescInstance, BufferData, Uniform, Storage, BindGroup, PushConstants, Varyings, FragmentOutput, PipelineConstants};
+use wrld::VertexLayout;
+use wrld::wgpu;
 
 #[repr(C)]
 #[derive(Desc, Debug)]
@@ -30,6 +32,214 @@ struct VertexDIBD {
     #[f32x4(1)] color: [f32; 4]
 }
 
+#[repr(C)]
+#[derive(Desc)]
+struct VertexPadded {
+    #[u8x2(0)] flags: [u8; 2],
+    #[f32(1)] weight: f32,
+    id: u64,
+    #[f32x3(2)] normal: [f32; 3]
+}
+
+#[repr(C)]
+#[derive(DescInstance)]
+struct VertexMatrix {
+    #[u32(0)] id: u32,
+    #[mat4x4(f32, 1)] transform: [[f32; 4]; 4]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+struct VertexInferred {
+    #[vertex(location = 0)] position: [f32; 3],
+    #[vertex(location = 1)] id: u32,
+    #[un8x4(2)] color: [u8; 4],
+    #[vertex(location = 3)] bones: [u8; 4],
+    #[vertex(location = 4)] transform: [[f32; 4]; 2]
+}
+
+mod engine {
+    pub mod gfx {
+        pub use wrld::wgpu;
+        pub use wrld::bytemuck;
+    }
+}
+
+#[repr(C)]
+#[derive(Desc, BufferData)]
+#[wrld(wgpu = "crate::engine::gfx::wgpu", bytemuck = "crate::engine::gfx::bytemuck")]
+struct VertexCratePath {
+    #[f32x3(0)] position: [f32; 3],
+    name: &'static str,
+    #[f32x2(1)] uv: [f32; 2]
+}
+
+#[cfg(feature = "shader")]
+wrld::vertex_from_wgsl!("tests/shaders/mesh.wgsl", "vs_main", #[derive(Debug, Clone, Copy, PartialEq)] MeshInput, "vertex");
+
+#[cfg(feature = "shader")]
+mod mesh {
+    wrld::vertex_from_wgsl!("tests/shaders/mesh.wgsl", "vs_main", #[wrld(wgpu = "crate::engine::gfx::wgpu")] pub(crate) MeshVertex, "vertex");
+}
+
+#[cfg(feature = "check")]
+#[repr(C)]
+#[derive(Desc)]
+struct MeshVertex {
+    #[f32x3(0)] position: [f32; 3],
+    #[u8x4(1)] color: [u8; 4],
+    #[f32x2(7)] uv: [f32; 2]
+}
+
+#[cfg(feature = "check")]
+#[repr(C)]
+#[derive(DescInstance)]
+struct MeshInstance {
+    #[mat4x4(f32, 3)] transform: [[f32; 4]; 4]
+}
+
+#[cfg(feature = "shader")]
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(shader = "tests/shaders/mesh.wgsl", entry = "vs_main", input = "vertex")]
+struct VertexShader {
+    #[f32x3(0)] position: [f32; 3],
+    data: u64,
+    #[un8x4(1)] color: [u8; 4],
+    #[u32(2)] id: u32
+}
+
+#[cfg(feature = "shader")]
+#[repr(C)]
+#[derive(DescInstance)]
+#[wrld(shader = "tests/shaders/mesh.wgsl", entry = "vs_main", input = "instance", auto_location = 3)]
+struct InstanceShader {
+    #[mat4x4(f32)] transform: [[f32; 4]; 4]
+}
+
+#[cfg(feature = "wgpu-latest")]
+#[repr(C)]
+#[derive(Desc)]
+struct VertexLatest {
+    #[vertex(location = 0)] id: u8,
+    #[sn16(1)] weight: i16,
+    #[un10_10_10_2(2)] normal: u32,
+    #[un8x4bgra(3)] color: [u8; 4]
+}
+
+#[repr(C)]
+#[derive(DescInstance, BufferData)]
+#[wrld(auto_location = 2)]
+struct VertexAutoLocation {
+    #[vertex] position: [f32; 3],
+    #[mat4x4(f32)] transform: [[f32; 4]; 4],
+    data: &'static str,
+    #[un8x4] color: [u8; 4],
+    #[f32x2(10)] uv: [f32; 2],
+    #[vertex] layer: u32
+}
+
+#[derive(Uniform)]
+struct Light {
+    position: [f32; 3],
+    intensity: f32,
+    color: [f32; 3],
+    normal: [[f32; 3]; 3],
+    uv: [[f32; 2]; 2],
+    id: u32,
+    corners: [[f32; 3]; 5],
+    layers: [[i32; 4]; 2]
+}
+
+#[derive(Storage, Clone, Copy)]
+struct Particle {
+    position: [f32; 3],
+    life: f32,
+    velocity: [f32; 3]
+}
+
+#[derive(Storage)]
+struct Emitter {
+    transform: [[f32; 3]; 3],
+    seed: u32,
+    spawn: Particle,
+    weights: [f32; 5]
+}
+
+#[derive(Storage)]
+struct ParticleSystem<'a> {
+    time: f32,
+    emitters: [Emitter; 2],
+    particles: &'a [Particle]
+}
+
+#[derive(Storage)]
+struct Weights {
+    color: [f32; 4],
+    values: Vec<f32>
+}
+
+#[derive(Storage)]
+struct Colors {
+    count: u32,
+    colors: Vec<[f32; 4]>
+}
+
+#[derive(Storage)]
+struct Indices<'a> {
+    offset: [f32; 3],
+    indices: &'a [u32]
+}
+
+#[derive(BindGroup)]
+#[wrld(visibility = "fragment")]
+struct MaterialBindings<'a> {
+    #[uniform(0, min_binding_size = Light, visibility = "vertex | fragment")] light: &'a wgpu::Buffer,
+    #[storage(1, read_only, min_binding_size = ParticleSystem)] particles: wgpu::BufferBinding<'a>,
+    #[texture(2, sample_type = "depth", dimension = "cube")] shadow: &'a wgpu::TextureView,
+    #[sampler(3, comparison)] shadow_sampler: &'a wgpu::Sampler,
+    name: &'static str
+}
+
+#[derive(PushConstants)]
+#[wrld(visibility = "vertex", max_push_constant_size = 96)]
+struct DrawData {
+    transform: [[f32; 4]; 4],
+    color: [f32; 3],
+    id: u32,
+    scale: [f32; 2]
+}
+
+// Varyings only describe the shader interface, the structure is never built.
+#[allow(dead_code)]
+#[derive(Varyings)]
+struct MeshVaryings {
+    #[builtin(position)] clip_position: [f32; 4],
+    uv: [f32; 2],
+    #[perspective(centroid)] normal: [f32; 3],
+    #[flat] material: u32,
+    #[location(5)] #[linear(sample)] depth: f32,
+    #[flat] layers: [i32; 2]
+}
+
+#[allow(dead_code)]
+#[derive(FragmentOutput)]
+struct GBuffer {
+    #[target(0, Rgba16Float)] normal: [f32; 4],
+    #[target(1, Rgba8UnormSrgb, blend = alpha, write_mask = "color")] albedo: [f32; 4],
+    #[target(3, R32Uint)] material: u32,
+    #[target(2, Rg16Float, blend = premultiplied_alpha)] velocity: [f32; 2]
+}
+
+#[derive(PipelineConstants)]
+struct Specialization {
+    #[constant(default = 16)] sample_count: u32,
+    #[constant(id = 3, default = -1.5)] exposure: f32,
+    #[constant(default = -2)] bias: i32,
+    #[constant(default = true)] shadows: bool,
+    seed: u32
+}
+
 const DESC_DATA : [VertexDBD; 2] = [
     VertexDBD { position: [0.0, 0.0], data: "hello", color: [1.0, 0.5, 0.5, 1.0]},
     VertexDBD { position: [1.0, 0.0], data: "hello", color: [1.0, 0.5, 0.5, 1.0]}
@@ -60,4 +270,738 @@ fn desc_buffer_data() {
 #[test]
 fn desc_instance_buffer_data() {
     println!("Result of mutate vertex desc instance buffer data : \n{:?}\n", mutate_vertex_d_i_b_d!(&DESC_INSTANCE_DATA));
-}
\ No newline at end of file
+}
+
+#[test]
+fn buffer_data_runtime() {
+    let vertices: Vec<VertexDBD> = (0..4).map(|i| VertexDBD {
+        position: [i as f32, 1.0],
+        data: "runtime",
+        color: [0.5; 4]
+    }).collect();
+
+    let packed: Vec<VertexDBDBufferData> = vertices.iter().collect();
+    let bytes = VertexDBD::pack(&vertices);
+
+    assert_eq!(packed.len(), 4);
+    assert!(packed.iter().zip(&vertices).all(|(packed, vertex)| packed == vertex));
+    assert_eq!(bytes, wrld::bytemuck::cast_slice::<VertexDBDBufferData, u8>(&packed));
+    assert_eq!(bytes, mutate_vertex_d_b_d!(&vertices));
+    assert_eq!(VertexDBD::pack(vertices.iter().filter(|vertex| vertex.position[0] > 1.0)).len(), 2 * 24);
+    assert_eq!(VertexDBDBufferData::from(&vertices[2]).position, [2.0, 1.0]);
+}
+
+#[test]
+fn buffer_data_write_packed() {
+    let mut scratch = vec![0xffu8; VertexDIBD::packed_len(4)];
+
+    assert_eq!(VertexDIBD::packed_len(4), 4 * 24);
+    assert_eq!(VertexDIBD::write_packed(&DESC_INSTANCE_DATA, &mut scratch), Ok(48));
+    assert_eq!(&scratch[..48], mutate_vertex_d_i_b_d!(&DESC_INSTANCE_DATA));
+    assert!(scratch[48..].iter().all(|byte| *byte == 0xff));
+
+    let mut small = [0u8; 30];
+    let error = VertexDIBD::write_packed(&DESC_INSTANCE_DATA, &mut small).unwrap_err();
+
+    assert_eq!(error, wrld::BufferTooSmall { required: 48, len: 30 });
+    assert_eq!(error.to_string(), "the destination is 30 bytes but the packed data need 48 bytes");
+    assert!(small.iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn buffer_data_unpack_into() {
+    let updated = [
+        VertexDBD { position: [4.0, 5.0], data: "gpu", color: [0.0, 1.0, 0.0, 1.0] },
+        VertexDBD { position: [6.0, 7.0], data: "gpu", color: [0.0, 0.0, 1.0, 1.0] }
+    ];
+
+    // Unaligned bytes, like a sub-slice of a readback buffer.
+    let mut bytes = vec![0u8];
+    bytes.extend(VertexDBD::pack(&updated));
+
+    let mut vertices = DESC_DATA;
+    VertexDBD::unpack_into(&bytes[1..], &mut vertices).unwrap();
+
+    assert!(vertices.iter().zip(&updated).all(|(vertex, updated)| VertexDBDBufferData::from(updated) == *vertex));
+    assert!(vertices.iter().all(|vertex| vertex.data == "hello"));
+
+    let mut vertices = DESC_DATA;
+
+    assert_eq!(
+        VertexDBD::unpack_into(&bytes[1..30], &mut vertices),
+        Err(wrld::UnpackError::PartialRecord { len: 29, stride: 24 })
+    );
+    assert_eq!(
+        VertexDBD::unpack_into(&bytes[1..25], &mut vertices),
+        Err(wrld::UnpackError::CountMismatch { records: 1, len: 2 })
+    );
+    assert_eq!(
+        wrld::UnpackError::CountMismatch { records: 1, len: 2 }.to_string(),
+        "the data hold 1 packed structures but the destination has 2 structures"
+    );
+    assert!(vertices.iter().zip(&DESC_DATA).all(|(vertex, original)| vertex.position == original.position));
+}
+
+#[test]
+fn desc_real_offsets() {
+    let desc = VertexPadded::desc();
+
+    assert_eq!(desc.array_stride, std::mem::size_of::<VertexPadded>() as u64);
+    assert_eq!(desc.attributes[0].offset, std::mem::offset_of!(VertexPadded, flags) as u64);
+    assert_eq!(desc.attributes[1].offset, std::mem::offset_of!(VertexPadded, weight) as u64);
+    assert_eq!(desc.attributes[2].offset, std::mem::offset_of!(VertexPadded, normal) as u64);
+}
+
+#[test]
+fn desc_matrix_offsets() {
+    let desc = VertexMatrix::desc();
+    let transform = std::mem::offset_of!(VertexMatrix, transform) as u64;
+
+    assert_eq!(desc.array_stride, std::mem::size_of::<VertexMatrix>() as u64);
+    assert_eq!(desc.attributes.len(), 5);
+
+    for (column, attribute) in desc.attributes[1..].iter().enumerate() {
+        assert_eq!(attribute.offset, transform + column as u64 * 16);
+        assert_eq!(attribute.shader_location, column as u32 + 1);
+    }
+}
+
+#[test]
+fn desc_buffer_data_layout() {
+    let desc = VertexDBD::desc();
+    let packed = VertexDBDBufferData::desc();
+
+    assert_eq!(desc.array_stride, std::mem::size_of::<VertexDBD>() as u64);
+    assert_eq!(desc.attributes[1].offset, std::mem::offset_of!(VertexDBD, color) as u64);
+
+    assert_eq!(packed.array_stride, 24);
+    assert_eq!(packed.step_mode, desc.step_mode);
+    assert_eq!(packed.attributes[0].offset, 0);
+    assert_eq!(packed.attributes[1].offset, 8);
+    assert_eq!(packed.array_stride as usize * DESC_DATA.len(), mutate_vertex_d_b_d!(&DESC_DATA).len());
+}
+
+#[test]
+fn desc_inferred_format() {
+    let desc = VertexInferred::desc();
+    let formats : Vec<wgpu::VertexFormat> = desc.attributes.iter().map(|a| a.format).collect();
+
+    assert_eq!(formats, vec![
+        wgpu::VertexFormat::Float32x3,
+        wgpu::VertexFormat::Uint32,
+        wgpu::VertexFormat::Unorm8x4,
+        wgpu::VertexFormat::Uint8x4,
+        wgpu::VertexFormat::Float32x4,
+        wgpu::VertexFormat::Float32x4
+    ]);
+    assert_eq!(desc.attributes[5].shader_location, 5);
+}
+
+#[test]
+fn desc_wgsl_struct() {
+    assert_eq!(Vertex::WGSL, "struct Vertex {\n    @location(0) position: vec2<f32>,\n    @location(1) color: vec4<f32>,\n}\n");
+    assert_eq!(VertexMatrix::WGSL, concat!(
+        "struct VertexMatrix {\n",
+        "    @location(0) id: u32,\n",
+        "    @location(1) transform_0: vec4<f32>,\n",
+        "    @location(2) transform_1: vec4<f32>,\n",
+        "    @location(3) transform_2: vec4<f32>,\n",
+        "    @location(4) transform_3: vec4<f32>,\n",
+        "}\n"
+    ));
+    assert_eq!(VertexInferred::WGSL, concat!(
+        "struct VertexInferred {\n",
+        "    @location(0) position: vec3<f32>,\n",
+        "    @location(1) id: u32,\n",
+        "    @location(2) color: vec4<f32>,\n",
+        "    @location(3) bones: vec4<u32>,\n",
+        "    @location(4) transform_0: vec4<f32>,\n",
+        "    @location(5) transform_1: vec4<f32>,\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn desc_glsl_hlsl() {
+    assert_eq!(VertexInferred::GLSL, concat!(
+        "layout(location = 0) in vec3 position;\n",
+        "layout(location = 1) in uint id;\n",
+        "layout(location = 2) in vec4 color;\n",
+        "layout(location = 3) in uvec4 bones;\n",
+        "layout(location = 4) in vec4 transform_0;\n",
+        "layout(location = 5) in vec4 transform_1;\n"
+    ));
+    assert_eq!(VertexInferred::HLSL, concat!(
+        "struct VertexInferred {\n",
+        "    float3 position : TEXCOORD0;\n",
+        "    uint id : TEXCOORD1;\n",
+        "    float4 color : TEXCOORD2;\n",
+        "    uint4 bones : TEXCOORD3;\n",
+        "    float4 transform_0 : TEXCOORD4;\n",
+        "    float4 transform_1 : TEXCOORD5;\n",
+        "};\n"
+    ));
+}
+
+#[cfg(feature = "shader")]
+#[test]
+fn desc_shader() {
+    assert_eq!(VertexShader::ID_LOCATION, 2);
+    assert_eq!(InstanceShader::TRANSFORM_LOCATION, 3);
+}
+
+#[test]
+fn uniform_layout() {
+    assert_eq!(Light::WGSL, concat!(
+        "struct Light {\n",
+        "    position: vec3<f32>,\n",
+        "    intensity: f32,\n",
+        "    color: vec3<f32>,\n",
+        "    normal: mat3x3<f32>,\n",
+        "    uv: mat2x2<f32>,\n",
+        "    id: u32,\n",
+        "    corners: array<vec3<f32>, 5>,\n",
+        "    layers: array<vec4<i32>, 2>,\n",
+        "}\n"
+    ));
+
+    assert_eq!(std::mem::offset_of!(LightUniform, intensity), 12);
+    assert_eq!(std::mem::offset_of!(LightUniform, color), 16);
+    assert_eq!(std::mem::offset_of!(LightUniform, normal), 32);
+    assert_eq!(std::mem::offset_of!(LightUniform, uv), 80);
+    assert_eq!(std::mem::offset_of!(LightUniform, id), 96);
+    assert_eq!(std::mem::offset_of!(LightUniform, corners), 112);
+    assert_eq!(std::mem::offset_of!(LightUniform, layers), 192);
+    assert_eq!(Light::MIN_BINDING_SIZE, wgpu::BufferSize::new(224));
+
+    let light = Light {
+        position: [1.0, 2.0, 3.0],
+        intensity: 4.0,
+        color: [0.5; 3],
+        normal: [[1.0, 2.0, 3.0]; 3],
+        uv: [[1.0, 0.0], [0.0, 1.0]],
+        id: 7,
+        corners: [[1.0; 3]; 5],
+        layers: [[1, 2, 3, 4], [5, 6, 7, 8]]
+    };
+
+    let uniform = LightUniform::from(&light);
+
+    assert_eq!(uniform.normal, [[1.0, 2.0, 3.0, 0.0]; 3]);
+    assert_eq!(uniform.corners, [[1.0, 1.0, 1.0, 0.0]; 5]);
+    assert_eq!(uniform.layers, light.layers);
+    assert_eq!(&wrld::bytemuck::bytes_of(&uniform)[96..100], &7u32.to_ne_bytes());
+}
+
+#[cfg(feature = "check")]
+#[test]
+fn uniform_naga_layout() {
+    use wgpu::naga;
+
+    let source = format!("{}@group(0) @binding(0) var<uniform> light: Light;\n", Light::WGSL);
+    let module = naga::front::wgsl::parse_str(&source).unwrap();
+
+    naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+        .validate(&module)
+        .unwrap();
+
+    let mut layouter = naga::proc::Layouter::default();
+    layouter.update(module.to_ctx()).unwrap();
+
+    let (handle, _) = module.types.iter().find(|(_, ty)| ty.name.as_deref() == Some("Light")).unwrap();
+    let naga::TypeInner::Struct { members, span } = &module.types[handle].inner else { unreachable!() };
+
+    assert_eq!(Some(*span as u64), Light::MIN_BINDING_SIZE.map(|size| size.get()));
+    assert_eq!(
+        members.iter().map(|m| m.offset).collect::<Vec<u32>>(),
+        vec![0, 12, 16, 32, 80, 96, 112, 192]
+    );
+}
+
+fn particle_system_emitter(seed: u32) -> Emitter {
+    Emitter {
+        transform: [[1.0, 2.0, 3.0]; 3],
+        seed,
+        spawn: Particle { position: [4.0, 5.0, 6.0], life: 7.0, velocity: [8.0; 3] },
+        weights: [0.5; 5]
+    }
+}
+
+#[test]
+fn storage_layout() {
+    assert_eq!((<Particle as wrld::Storage>::ALIGN, <Particle as wrld::Storage>::SIZE), (16, 32));
+    assert_eq!((<Emitter as wrld::Storage>::ALIGN, <Emitter as wrld::Storage>::SIZE), (16, 128));
+    assert_eq!(Emitter::WGSL, concat!(
+        "struct Emitter {\n",
+        "    transform: mat3x3<f32>,\n",
+        "    seed: u32,\n",
+        "    spawn: Particle,\n",
+        "    weights: array<f32, 5>,\n",
+        "}\n"
+    ));
+    assert_eq!(ParticleSystem::WGSL, concat!(
+        "struct ParticleSystem {\n",
+        "    time: f32,\n",
+        "    emitters: array<Emitter, 2>,\n",
+        "    particles: array<Particle>,\n",
+        "}\n"
+    ));
+    assert_eq!(ParticleSystem::storage_size(3), 272 + 3 * 32);
+    assert_eq!(ParticleSystem::MIN_BINDING_SIZE, wgpu::BufferSize::new(304));
+
+    let particles = [Particle { position: [1.0; 3], life: 2.0, velocity: [3.0; 3] }; 2];
+    let system = ParticleSystem {
+        time: 0.5,
+        emitters: [particle_system_emitter(1), particle_system_emitter(2)],
+        particles: &particles
+    };
+
+    let bytes = system.to_storage_bytes();
+    let read = |offset: usize| f32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+    assert_eq!(bytes.len(), 336);
+    assert_eq!(read(0), 0.5);
+    // Second column of the transform of the first emitter, columns are padded to 16 bytes.
+    assert_eq!((read(16 + 16), read(16 + 28)), (1.0, 0.0));
+    assert_eq!(&bytes[16 + 48..16 + 52], &1u32.to_ne_bytes());
+    assert_eq!(read(16 + 64 + 12), 7.0);
+    assert_eq!(read(16 + 96 + 16), 0.5);
+    assert_eq!(&bytes[144 + 48..144 + 52], &2u32.to_ne_bytes());
+    assert_eq!((read(272 + 32 + 12), read(272 + 32 + 16)), (2.0, 3.0));
+}
+
+#[test]
+fn storage_runtime_array_elements() {
+    // The header alignment (16) is bigger than the element stride (4), the size is rounded up to it.
+    assert_eq!(Weights::WGSL, "struct Weights {\n    color: vec4<f32>,\n    values: array<f32>,\n}\n");
+    assert_eq!(Weights::storage_size(1), 32);
+    assert_eq!(Weights::storage_size(4), 32);
+    assert_eq!(Weights::storage_size(5), 48);
+    assert_eq!(Weights::MIN_BINDING_SIZE, wgpu::BufferSize::new(32));
+
+    let weights = Weights { color: [1.0; 4], values: vec![2.0] };
+    let bytes = weights.to_storage_bytes();
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(&bytes[16..20], &2.0f32.to_ne_bytes());
+    assert!(bytes[20..].iter().all(|byte| *byte == 0));
+
+    let colors = Colors { count: 2, colors: vec![[0.5; 4], [0.25; 4]] };
+    let bytes = colors.to_storage_bytes();
+
+    assert_eq!(Colors::storage_size(2), 48);
+    assert_eq!(bytes.len(), 48);
+    assert_eq!(&bytes[0..4], &2u32.to_ne_bytes());
+    assert_eq!(&bytes[16..32], wrld::bytemuck::bytes_of(&[0.5f32; 4]));
+    assert_eq!(&bytes[32..48], wrld::bytemuck::bytes_of(&[0.25f32; 4]));
+
+    let indices = Indices { offset: [1.0; 3], indices: &[7, 8, 9] };
+    let bytes = indices.to_storage_bytes();
+
+    assert_eq!(Indices::storage_size(3), 32);
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(&bytes[12..24], wrld::bytemuck::cast_slice::<u32, u8>(&[7, 8, 9]));
+}
+
+#[cfg(feature = "check")]
+#[test]
+fn storage_naga_layout() {
+    use wgpu::naga;
+
+    let source = format!(
+        "{}{}{}@group(0) @binding(0) var<storage, read> system: ParticleSystem;\n",
+        Particle::WGSL, Emitter::WGSL, ParticleSystem::WGSL
+    );
+    let module = naga::front::wgsl::parse_str(&source).unwrap();
+
+    naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+        .validate(&module)
+        .unwrap();
+
+    let mut layouter = naga::proc::Layouter::default();
+    layouter.update(module.to_ctx()).unwrap();
+
+    let layout = |name: &str| {
+        let (handle, _) = module.types.iter().find(|(_, ty)| ty.name.as_deref() == Some(name)).unwrap();
+        let naga::TypeInner::Struct { members, span } = &module.types[handle].inner else { unreachable!() };
+
+        (members.iter().map(|m| m.offset as u64).collect::<Vec<u64>>(), layouter[handle].alignment, *span as u64)
+    };
+
+    let (offsets, align, size) = layout("Emitter");
+    assert_eq!(offsets, Emitter::WRLD_STORAGE_LAYOUT.0.to_vec());
+    assert_eq!(Some(align), naga::proc::Alignment::new(Emitter::WRLD_STORAGE_LAYOUT.1 as u32));
+    assert_eq!(size, Emitter::WRLD_STORAGE_LAYOUT.2);
+
+    let (members, _, _) = layout("ParticleSystem");
+    assert_eq!(members, ParticleSystem::WRLD_STORAGE_LAYOUT.0.to_vec());
+
+    // Span of a structure ending with a runtime-sized array holding one element.
+    let source = format!("{}@group(0) @binding(0) var<storage, read> weights: Weights;\n", Weights::WGSL);
+    let module = naga::front::wgsl::parse_str(&source).unwrap();
+    let (_, ty) = module.types.iter().find(|(_, ty)| ty.name.as_deref() == Some("Weights")).unwrap();
+    let naga::TypeInner::Struct { span, .. } = &ty.inner else { unreachable!() };
+
+    assert_eq!(*span as u64, Weights::storage_size(1));
+}
+
+#[test]
+fn bind_group_layout() {
+    assert_eq!(MaterialBindings::LAYOUT_ENTRIES, &[
+        wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: Light::MIN_BINDING_SIZE
+            },
+            count: None
+        },
+        wgpu::BindGroupLayoutEntry {
+            binding: 1,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: ParticleSystem::MIN_BINDING_SIZE
+            },
+            count: None
+        },
+        wgpu::BindGroupLayoutEntry {
+            binding: 2,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Depth,
+                view_dimension: wgpu::TextureViewDimension::Cube,
+                multisampled: false
+            },
+            count: None
+        },
+        wgpu::BindGroupLayoutEntry {
+            binding: 3,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Comparison),
+            count: None
+        }
+    ]);
+
+    assert_eq!(MaterialBindings::layout_descriptor(Some("material")).entries.len(), 4);
+}
+
+#[cfg(feature = "wgpu-latest")]
+#[test]
+fn bind_group_entries() {
+    let (device, _) = wgpu::Device::noop(&wgpu::DeviceDescriptor::default());
+
+    let buffer = |size: u64| device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size,
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::STORAGE,
+        mapped_at_creation: false
+    });
+
+    let light = buffer(Light::MIN_BINDING_SIZE.unwrap().get());
+    let particles = buffer(ParticleSystem::storage_size(16));
+
+    let shadow = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d { width: 4, height: 4, depth_or_array_layers: 6 },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Depth32Float,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[]
+    });
+
+    let shadow = shadow.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::Cube),
+        ..Default::default()
+    });
+
+    let shadow_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        compare: Some(wgpu::CompareFunction::Less),
+        ..Default::default()
+    });
+
+    let bindings = MaterialBindings {
+        light: &light,
+        particles: wgpu::BufferBinding { buffer: &particles, offset: 0, size: None },
+        shadow: &shadow,
+        shadow_sampler: &shadow_sampler,
+        name: "material"
+    };
+
+    let layout = device.create_bind_group_layout(&MaterialBindings::layout_descriptor(Some(bindings.name)));
+    let entries = bindings.entries();
+
+    assert_eq!(entries.iter().map(|entry| entry.binding).collect::<Vec<u32>>(), vec![0, 1, 2, 3]);
+
+    // Validation errors are uncaptured errors, making the test panic.
+    let _bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor { label: None, layout: &layout, entries: &entries });
+}
+
+#[test]
+fn push_constants() {
+    // Push constants are immediates with wgpu 30.
+    #[cfg(feature = "wgpu-latest")]
+    let declaration = "var<immediate> draw_data: DrawData;\n";
+    #[cfg(not(feature = "wgpu-latest"))]
+    let declaration = "var<push_constant> draw_data: DrawData;\n";
+
+    assert_eq!((DrawData::SIZE, DrawData::ALIGN), (96, 16));
+    assert_eq!(DrawData::WGSL, format!(concat!(
+        "struct DrawData {{\n",
+        "    transform: mat4x4<f32>,\n",
+        "    color: vec3<f32>,\n",
+        "    id: u32,\n",
+        "    scale: vec2<f32>,\n",
+        "}}\n",
+        "{}"
+    ), declaration));
+
+    #[cfg(feature = "wgpu-latest")]
+    assert_eq!(DrawData::STAGES, wgpu::ShaderStages::VERTEX);
+    #[cfg(not(feature = "wgpu-latest"))]
+    assert_eq!(DrawData::PUSH_CONSTANT_RANGE, wgpu::PushConstantRange { stages: wgpu::ShaderStages::VERTEX, range: 0..96 });
+
+    let draw = DrawData { transform: [[1.0; 4]; 4], color: [0.5; 3], id: 3, scale: [2.0, 4.0] };
+    let bytes = draw.bytes();
+
+    assert_eq!(bytes.len(), 96);
+    assert_eq!(&bytes[64..68], &0.5f32.to_ne_bytes());
+    assert_eq!(&bytes[76..80], &3u32.to_ne_bytes());
+    assert_eq!(&bytes[80..84], &2.0f32.to_ne_bytes());
+}
+
+#[test]
+fn varyings() {
+    assert_eq!(MeshVaryings::WGSL, concat!(
+        "struct MeshVaryings {\n",
+        "    @builtin(position) clip_position: vec4<f32>,\n",
+        "    @location(0) uv: vec2<f32>,\n",
+        "    @location(1) @interpolate(perspective, centroid) normal: vec3<f32>,\n",
+        "    @location(2) @interpolate(flat) material: u32,\n",
+        "    @location(5) @interpolate(linear, sample) depth: f32,\n",
+        "    @location(6) @interpolate(flat) layers: vec2<i32>,\n",
+        "}\n"
+    ));
+
+    assert_eq!(
+        [MeshVaryings::UV_LOCATION, MeshVaryings::NORMAL_LOCATION, MeshVaryings::MATERIAL_LOCATION, MeshVaryings::DEPTH_LOCATION, MeshVaryings::LAYERS_LOCATION],
+        [0, 1, 2, 5, 6]
+    );
+}
+
+#[cfg(feature = "check")]
+#[test]
+fn varyings_naga() {
+    use wgpu::naga;
+
+    let source = format!(concat!(
+        "{}",
+        "@vertex fn vs_main(@location(0) position: vec3<f32>) -> MeshVaryings {{\n",
+        "    return MeshVaryings(vec4<f32>(position, 1.0), vec2<f32>(0.0), vec3<f32>(0.0), 1u, 0.5, vec2<i32>(0));\n",
+        "}}\n",
+        "@fragment fn fs_main(in: MeshVaryings) -> @location(0) vec4<f32> {{\n",
+        "    return vec4<f32>(in.normal, f32(in.material));\n",
+        "}}\n"
+    ), MeshVaryings::WGSL);
+
+    let module = naga::front::wgsl::parse_str(&source).unwrap();
+
+    naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::all())
+        .validate(&module)
+        .unwrap();
+}
+
+#[test]
+fn fragment_output() {
+    assert_eq!(GBuffer::WGSL, concat!(
+        "struct GBuffer {\n",
+        "    @location(0) normal: vec4<f32>,\n",
+        "    @location(1) albedo: vec4<f32>,\n",
+        "    @location(3) material: u32,\n",
+        "    @location(2) velocity: vec2<f32>,\n",
+        "}\n"
+    ));
+
+    assert_eq!(GBuffer::TARGETS, [
+        Some(wgpu::ColorTargetState {
+            format: wgpu::TextureFormat::Rgba16Float,
+            blend: None,
+            write_mask: wgpu::ColorWrites::ALL
+        }),
+        Some(wgpu::ColorTargetState {
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+            write_mask: wgpu::ColorWrites::COLOR
+        }),
+        Some(wgpu::ColorTargetState {
+            format: wgpu::TextureFormat::Rg16Float,
+            blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            write_mask: wgpu::ColorWrites::ALL
+        }),
+        Some(wgpu::ColorTargetState {
+            format: wgpu::TextureFormat::R32Uint,
+            blend: None,
+            write_mask: wgpu::ColorWrites::ALL
+        })
+    ]);
+}
+
+#[test]
+fn pipeline_constants() {
+    assert_eq!(Specialization::WGSL, concat!(
+        "override sample_count: u32 = 16u;\n",
+        "@id(3) override exposure: f32 = -1.5;\n",
+        "override bias: i32 = -2;\n",
+        "override shadows: bool = true;\n",
+        "override seed: u32;\n"
+    ));
+
+    let specialization = Specialization { sample_count: 4, exposure: 0.5, bias: 1, shadows: false, seed: 2 };
+
+    assert_eq!(
+        specialization.constant_entries(),
+        [("sample_count", 4.0), ("3", 0.5), ("bias", 1.0), ("shadows", 0.0), ("seed", 2.0)]
+    );
+    assert_eq!(specialization.constants(), std::collections::HashMap::from([
+        (String::from("sample_count"), 4.0),
+        (String::from("3"), 0.5),
+        (String::from("bias"), 1.0),
+        (String::from("shadows"), 0.0),
+        (String::from("seed"), 2.0)
+    ]));
+}
+
+// naga 0.19 has no overrides.
+#[cfg(all(feature = "check", feature = "wgpu-latest"))]
+#[test]
+fn pipeline_constants_naga() {
+    use wgpu::naga;
+
+    let source = format!(concat!(
+        "{}",
+        "@compute @workgroup_size(1) fn cs_main() {{\n",
+        "    let value = f32(sample_count) * exposure + f32(bias) + select(0.0, 1.0, shadows) + f32(seed);\n",
+        "}}\n"
+    ), Specialization::WGSL);
+
+    let module = naga::front::wgsl::parse_str(&source).unwrap();
+
+    naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::all())
+        .validate(&module)
+        .unwrap();
+
+    assert_eq!(module.overrides.iter().filter_map(|(_, o)| o.id).collect::<Vec<u16>>(), vec![3]);
+}
+
+#[cfg(feature = "check")]
+#[test]
+fn check_vertex_shader() {
+    let module = wgpu::naga::front::wgsl::parse_str(include_str!("shaders/mesh.wgsl")).unwrap();
+
+    let report = wrld::check_vertex_shader(&[MeshVertex::desc(), MeshInstance::desc()], &module, "vs_main").unwrap();
+
+    assert!(!report.is_ok());
+    assert_eq!(report.missing, vec![wrld::MissingLocation { location: 2, name: Some("id".to_string()) }]);
+    assert_eq!(report.unused, vec![wrld::UnusedAttribute { buffer: 0, location: 7, format: wgpu::VertexFormat::Float32x2 }]);
+    assert_eq!(report.mismatches, vec![wrld::KindMismatch {
+        buffer: 0,
+        location: 1,
+        format: wgpu::VertexFormat::Uint8x4,
+        name: Some("color".to_string()),
+        input: Some(wrld::ScalarKind::Float)
+    }]);
+
+    let report = wrld::check_vertex_shader(&[Vertex::desc()], &module, "vs_position").unwrap();
+
+    assert!(!report.is_ok());
+    assert_eq!(report.mismatches, vec![]);
+    assert_eq!(report.unused.len(), 1);
+    assert_eq!(report.to_string(), "attribute Float32x4 at location 1 of buffer 0 is not read by the shader\n");
+
+    assert!(wrld::check_vertex_shader(&[Vertex::desc()], &module, "fs_main").is_err());
+}
+
+#[cfg(feature = "shader")]
+#[test]
+fn vertex_from_wgsl() {
+    let vertex = MeshInput { position: [0.0, 1.0, 2.0], color: [1.0; 4], id: 3 };
+
+    assert_eq!(vertex, MeshInput { position: [0.0, 1.0, 2.0], color: [1.0; 4], id: 3 });
+    assert_eq!(MeshInput::desc().array_stride, 32);
+    assert_eq!(MeshInput::desc().attributes[2].format, wgpu::VertexFormat::Uint32);
+    assert_eq!(MeshInput::WGSL, concat!(
+        "struct MeshInput {\n",
+        "    @location(0) position: vec3<f32>,\n",
+        "    @location(1) color: vec4<f32>,\n",
+        "    @location(2) id: u32,\n",
+        "}\n"
+    ));
+
+    // The fields have the visibility of the structure, the #[wrld] attribute is given to Desc.
+    let vertex = mesh::MeshVertex { position: [0.0; 3], color: [1.0; 4], id: 3 };
+
+    assert_eq!(vertex.id, 3);
+    assert_eq!(mesh::MeshVertex::desc().attributes, MeshInput::desc().attributes);
+}
+
+#[test]
+fn desc_crate_path() {
+    let data = VertexCratePath { position: [1.0, 2.0, 3.0], name: "vertex", uv: [4.0, 5.0] };
+    let packed = VertexCratePathBufferData::from(data);
+
+    assert_eq!(VertexCratePath::desc().attributes[1].offset, std::mem::offset_of!(VertexCratePath, uv) as u64);
+    assert_eq!(VertexCratePathBufferData::desc().array_stride, 20);
+    assert_eq!(wrld::bytemuck::bytes_of(&packed).len(), 20);
+}
+
+#[cfg(feature = "wgpu-latest")]
+#[test]
+fn desc_latest_formats() {
+    let desc = VertexLatest::desc();
+    let formats : Vec<wgpu::VertexFormat> = desc.attributes.iter().map(|a| a.format).collect();
+
+    assert_eq!(formats, vec![
+        wgpu::VertexFormat::Uint8,
+        wgpu::VertexFormat::Snorm16,
+        wgpu::VertexFormat::Unorm10_10_10_2,
+        wgpu::VertexFormat::Unorm8x4Bgra
+    ]);
+    assert_eq!(desc.attributes[3].offset, std::mem::offset_of!(VertexLatest, color) as u64);
+}
+
+#[test]
+fn desc_auto_location() {
+    let locations : Vec<u32> = VertexAutoLocation::desc().attributes.iter().map(|a| a.shader_location).collect();
+
+    assert_eq!(locations, vec![2, 3, 4, 5, 6, 7, 10, 11]);
+    assert_eq!(VertexAutoLocation::POSITION_LOCATION, 2);
+    assert_eq!(VertexAutoLocation::TRANSFORM_LOCATION, 3);
+    assert_eq!(VertexAutoLocation::COLOR_LOCATION, 7);
+    assert_eq!(VertexAutoLocation::UV_LOCATION, 10);
+    assert_eq!(VertexAutoLocation::LAYER_LOCATION, 11);
+    assert_eq!(Vertex::COLOR_LOCATION, 1);
+
+    let packed : Vec<u32> = VertexAutoLocationBufferData::desc().attributes.iter().map(|a| a.shader_location).collect();
+    assert_eq!(packed, locations);
+}
+
+fn pipeline_buffers<V: VertexLayout, I: VertexLayout>() -> [wgpu::VertexBufferLayout<'static>; 2] {
+    [V::LAYOUT, I::LAYOUT]
+}
+
+#[test]
+fn vertex_layout_trait() {
+    let [vertex, instance] = pipeline_buffers::<Vertex, VertexInstance>();
+
+    assert_eq!(vertex, Vertex::desc());
+    assert_eq!(instance, VertexInstance::desc());
+    assert_eq!(VertexInstance::STEP_MODE, wgpu::VertexStepMode::Instance);
+    assert_eq!(VertexDBDBufferData::STEP_MODE, VertexDBD::STEP_MODE);
+    assert_eq!(VertexMatrix::ATTRIBUTES.len(), 5);
+    assert_eq!(VertexPadded::ARRAY_STRIDE, std::mem::size_of::<VertexPadded>() as u64);
+}
@@ -0,0 +1,6 @@
+enable f16;
+
+@vertex
+fn vs_main(@location(0) position: vec3<f32>, @location(1) uv: vec2<f16>) -> @builtin(position) vec4<f32> {
+    return vec4<f32>(position, f32(uv.x));
+}
@@ -0,0 +1,12 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+
+    #[cfg(feature = "shader")]
+    t.compile_fail("tests/ui/shader/*.rs");
+
+    // Formats that only exist in newer wgpu versions.
+    #[cfg(not(feature = "wgpu-latest"))]
+    t.compile_fail("tests/ui/wgpu_0_19/*.rs");
+}
@@ -0,0 +1,12 @@
+use wrld::wgpu;
+
+#[derive(wrld::BindGroup)]
+struct Bindings<'a> {
+    #[uniform(0, dynamic)] params: &'a wgpu::Buffer,
+    #[texture(0)] albedo: &'a wgpu::TextureView,
+    #[texture(1, dimension = "4d")] normal: &'a wgpu::TextureView,
+    #[sampler(2, visibility = "vertex | geometry")] sampler: &'a wgpu::Sampler,
+    #[storage(3, readonly)] lights: &'a wgpu::Buffer
+}
+
+fn main() {}
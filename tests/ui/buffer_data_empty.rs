@@ -0,0 +1,9 @@
+use wrld::{BufferData, Desc};
+
+#[repr(C)]
+#[derive(Clone, Copy, Desc, BufferData)]
+struct Empty {
+    padding: [f32; 4]
+}
+
+fn main() {}
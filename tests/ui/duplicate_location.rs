@@ -0,0 +1,12 @@
+use wrld::Desc;
+
+#[repr(C)]
+#[derive(Desc)]
+struct Vertex {
+    #[f32x2(0)] position: [f32; 2],
+    #[f32x4(0)] color: [f32; 4],
+    #[mat2x2(f32, 2)] transform: [[f32; 2]; 2],
+    #[f32(3)] weight: f32
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(wrld::FragmentOutput)]
+struct GBuffer {
+    #[target(0, Rgba16Float)] normal: [f32; 4],
+    #[target(0, Rgba8Unorm)] albedo: [f32; 4],
+    #[target(1, R32Uint, blend = alpha)] material: u32,
+    #[target(2, Rgba8Uint)] color: [f32; 4],
+    #[target(3, Depth32Float)] depth: f32,
+    #[target(4, Rgba8Unorm, blend = additive)] light: [f32; 4],
+    #[target(5, Rgba32Float)] transform: [[f32; 4]; 4],
+    name: f32
+}
+
+fn main() {}
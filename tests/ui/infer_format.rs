@@ -0,0 +1,12 @@
+use wrld::Desc;
+
+#[repr(C)]
+#[derive(Desc)]
+struct Vertex {
+    #[vertex(location = 0)] position: [f32; 5],
+    #[vertex(location = 1)] name: &'static str,
+    #[vertex(location = 2)] #[un8x4(3)] color: [u8; 4],
+    #[vertex(index = 4)] uv: [f32; 2]
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use wrld::Desc;
+
+#[repr(C)]
+#[derive(Desc)]
+struct Vertex {
+    /// Doc comments are not vertex attributes.
+    #[f32x2("0")] position: [f32; 2],
+    #[mat4x3(u8, 1)] transform: [[u8; 3]; 4],
+    #[f32x4(uv)] color: [f32; 4]
+}
+
+fn main() {}
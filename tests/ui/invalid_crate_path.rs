@@ -0,0 +1,10 @@
+use wrld::Desc;
+
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(wgpu = "my engine::wgpu")]
+struct Vertex {
+    #[f32x2(0)] position: [f32; 2]
+}
+
+fn main() {}
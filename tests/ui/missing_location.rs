@@ -0,0 +1,25 @@
+use wrld::Desc;
+
+#[repr(C)]
+#[derive(Desc)]
+struct Vertex {
+    #[f32x2] position: [f32; 2],
+    #[vertex] color: [f32; 4]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(auto_location = 1, locations)]
+struct Instance {
+    #[f32x2] position: [f32; 2]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(auto_location)]
+struct Overlap {
+    #[mat2x2(f32)] transform: [[f32; 2]; 2],
+    #[f32(1)] weight: f32
+}
+
+fn main() {}
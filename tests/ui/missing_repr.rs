@@ -0,0 +1,13 @@
+use wrld::Desc;
+
+#[derive(Desc)]
+struct Vertex {
+    #[f32x2(0)] position: [f32; 2]
+}
+
+#[derive(Desc)]
+enum NotAStruct {
+    Position
+}
+
+fn main() {}
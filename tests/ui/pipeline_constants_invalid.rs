@@ -0,0 +1,13 @@
+#[derive(wrld::PipelineConstants)]
+struct Specialization {
+    #[constant(id = 0)] exposure: f32,
+    #[constant(id = 0)] gamma: f32,
+    #[constant(default = -1)] count: u32,
+    #[constant(default = 1.5)] layer: i32,
+    #[constant(default = "on")] shadows: bool,
+    #[constant(seed = 3)] seed: u32,
+    color: [f32; 3],
+    precision: f64
+}
+
+fn main() {}
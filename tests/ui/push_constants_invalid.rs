@@ -0,0 +1,15 @@
+#[derive(wrld::PushConstants)]
+#[wrld(visibility = "vertex | geometry")]
+struct DrawData {
+    transform: [[f32; 4]; 4],
+    name: String
+}
+
+#[derive(wrld::PushConstants)]
+#[wrld(max_push_constant_size = 64)]
+struct Transforms {
+    model: [[f32; 4]; 4],
+    view: [[f32; 4]; 4]
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use wrld::Desc;
+
+// trybuild compile the tests from target/tests/trybuild/wrld
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(shader = "../../../../tests/shaders/mesh.wgsl")]
+struct NoEntry {
+    #[f32x3(0)] position: [f32; 3]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(shader = "../../../../tests/shaders/mesh.wgsl", entry = "fs_main")]
+struct UnknownEntry {
+    #[f32x3(0)] position: [f32; 3]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(shader = "../../../../tests/shaders/mesh.wgsl", entry = "vs_main", input = "uv")]
+struct UnknownInput {
+    #[f32x3(0)] position: [f32; 3]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(shader = "shaders/missing.wgsl")]
+struct MissingShader {
+    #[f32x3(0)] position: [f32; 3]
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use wrld::Desc;
+
+// trybuild compile the tests from target/tests/trybuild/wrld
+#[repr(C)]
+#[derive(Desc)]
+#[wrld(shader = "../../../../tests/shaders/mesh.wgsl", entry = "vs_main", input = "vertex")]
+struct Vertex {
+    #[f32x3(0)] position: [f32; 3],
+    #[u8x4(1)] color: [u8; 4],
+    #[f32x2(5)] uv: [f32; 2]
+}
+
+fn main() {}
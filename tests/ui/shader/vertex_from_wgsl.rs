@@ -0,0 +1,6 @@
+// trybuild compile the tests from target/tests/trybuild/wrld
+wrld::vertex_from_wgsl!("../../../../tests/shaders/half.wgsl", "vs_main", Vertex);
+
+wrld::vertex_from_wgsl!("../../../../tests/shaders/mesh.wgsl", "vs_main", Instance, "instances");
+
+fn main() {}
@@ -0,0 +1,16 @@
+use wrld::Desc;
+
+#[repr(C)]
+#[derive(Desc)]
+struct Vertex {
+    #[f32x2(0)] position: [f32; 2],
+    #[f32x4(1)] color: [f32; 3]
+}
+
+#[repr(C)]
+#[derive(Desc)]
+struct Instance {
+    #[mat4x4(f32, 0)] transform: [[f32; 3]; 4]
+}
+
+fn main() {}
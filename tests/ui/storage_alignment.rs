@@ -0,0 +1,16 @@
+struct Odd;
+
+impl wrld::Storage for Odd {
+    const ALIGN: u64 = 12;
+    const SIZE: u64 = 12;
+
+    fn write_storage(&self, _dst: &mut [u8]) {}
+}
+
+#[derive(wrld::Storage)]
+struct Holder {
+    id: u32,
+    odd: Odd
+}
+
+fn main() {}
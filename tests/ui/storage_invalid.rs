@@ -0,0 +1,8 @@
+#[derive(wrld::Storage)]
+struct Lights {
+    lights: Vec<[f32; 4]>,
+    count: u32,
+    name: &'static str
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[derive(wrld::Uniform)]
+struct Light {
+    position: [f32; 3],
+    name: String,
+    weights: [f32; 8],
+    layers: [[u32; 2]; 4]
+}
+
+#[derive(wrld::Uniform)]
+struct Borrowed<'a> {
+    intensity: &'a f32
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[derive(wrld::Varyings)]
+struct VertexOutput {
+    #[builtin(position)] clip_position: [f32; 3],
+    id: u32,
+    #[perspective] layers: [i32; 2],
+    #[flat] #[linear] weight: f32,
+    #[linear(flat)] depth: f32,
+    transform: [[f32; 4]; 4],
+    #[location(0)] uv: [f32; 2],
+    #[builtin(front_facing)] front: u32
+}
+
+#[derive(wrld::Varyings)]
+struct FragmentInput {
+    #[builtin(position)] position: [f32; 4],
+    #[builtin(position)] clip_position: [f32; 4]
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[repr(C)]
+#[derive(wrld::Desc)]
+struct Vertex {
+    #[f32x3(0)] position: [f32; 3],
+    #[un8x4bgra(1)] color: [u8; 4],
+    #[vertex(location = 2)] id: u8
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#[repr(C)]
+#[derive(wrld::Desc)]
+#[wrld(visibility = "vertex")]
+struct Vertex {
+    #[f32x3(0)] position: [f32; 3]
+}
+
+#[derive(wrld::Uniform)]
+#[wrld(shader = "shaders/mesh.wgsl")]
+struct Camera {
+    view_proj: [[f32; 4]; 4]
+}
+
+#[derive(wrld::BindGroup)]
+#[wrld(visibility = "fragment", max_push_constant_size = 256)]
+struct Material<'a> {
+    #[sampler(0)] sampler: &'a wrld::wgpu::Sampler
+}
+
+#[derive(wrld::Varyings)]
+#[wrld(auto_location = 1, wgpu = "wgpu")]
+struct VertexOutput {
+    uv: [f32; 2]
+}
+
+fn main() {}
@@ -0,0 +1,86 @@
+use wrld::{BufferData, Desc, DescInstance, FragmentOutput, PushConstants, Storage, Uniform, VertexLayout};
+
+#[repr(C)]
+#[derive(Clone, Copy, Desc, BufferData)]
+struct Vertex {
+    #[f32x3(0)] position: [f32; 3],
+    name: &'static str,
+    #[un8x4(1)] color: [u8; 4]
+}
+
+#[repr(C)]
+#[derive(DescInstance)]
+struct Instance {
+    #[mat4x4(f32, 2)] transform: [[f32; 4]; 4]
+}
+
+#[derive(Uniform)]
+struct Camera {
+    view_proj: [[f32; 4]; 4],
+    position: [f32; 3]
+}
+
+#[derive(Storage)]
+struct Lights {
+    count: u32,
+    colors: Vec<[f32; 4]>
+}
+
+#[derive(PushConstants)]
+#[wrld(visibility = "vertex")]
+struct DrawData {
+    color: [f32; 3],
+    id: u32
+}
+
+#[allow(dead_code)]
+#[derive(FragmentOutput)]
+struct Output {
+    #[target(0, Rgba8UnormSrgb, blend = alpha)] color: [f32; 4]
+}
+
+#[test]
+fn desc() {
+    let desc = Vertex::desc();
+
+    assert_eq!(desc.array_stride, std::mem::size_of::<Vertex>() as wgpu::BufferAddress);
+    assert_eq!(desc.step_mode, wgpu::VertexStepMode::Vertex);
+    assert_eq!(desc.attributes, &[
+        wgpu::VertexAttribute { format: wgpu::VertexFormat::Float32x3, offset: 0, shader_location: 0 },
+        wgpu::VertexAttribute { format: wgpu::VertexFormat::Unorm8x4, offset: std::mem::offset_of!(Vertex, color) as u64, shader_location: 1 }
+    ]);
+
+    assert_eq!(Instance::desc().step_mode, wgpu::VertexStepMode::Instance);
+    assert_eq!(<Instance as VertexLayout>::ATTRIBUTES.len(), 4);
+}
+
+#[test]
+fn buffer_data() {
+    let vertices = [Vertex { position: [1.0, 2.0, 3.0], name: "a", color: [1, 2, 3, 4] }];
+
+    assert_eq!(VertexBufferData::desc().array_stride, 16);
+    assert_eq!(Vertex::pack(&vertices).len(), Vertex::packed_len(1));
+}
+
+#[test]
+fn uniform_and_storage() {
+    assert_eq!(Camera::MIN_BINDING_SIZE, wgpu::BufferSize::new(80));
+    assert_eq!(Lights::MIN_BINDING_SIZE, wgpu::BufferSize::new(Lights::storage_size(1)));
+    assert_eq!(Lights { count: 2, colors: vec![[1.0; 4]; 2] }.to_storage_bytes().len(), 48);
+}
+
+#[test]
+fn push_constants() {
+    assert!(DrawData::WGSL.ends_with("var<push_constant> draw_data: DrawData;\n"));
+    assert_eq!(DrawData::PUSH_CONSTANT_RANGE, wgpu::PushConstantRange { stages: wgpu::ShaderStages::VERTEX, range: 0..16 });
+    assert_eq!(DrawData { color: [1.0; 3], id: 7 }.bytes()[12..], 7u32.to_ne_bytes());
+}
+
+#[test]
+fn fragment_output() {
+    assert_eq!(Output::TARGETS, [Some(wgpu::ColorTargetState {
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+        write_mask: wgpu::ColorWrites::ALL
+    })]);
+}
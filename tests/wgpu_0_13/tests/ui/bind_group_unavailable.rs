@@ -0,0 +1,6 @@
+#[derive(wrld::BindGroup)]
+struct Material<'a> {
+    #[texture(0)] albedo: &'a wgpu::TextureView
+}
+
+fn main() {}
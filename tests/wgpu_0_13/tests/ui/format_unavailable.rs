@@ -0,0 +1,8 @@
+#[repr(C)]
+#[derive(wrld::Desc)]
+struct Vertex {
+    #[f32x3(0)] position: [f32; 3],
+    #[un10_10_10_2(1)] normal: u32
+}
+
+fn main() {}
@@ -0,0 +1,275 @@
+use crate::macros::{combine_errors, get_named_fields, get_wrld_attrs};
+use crate::format::WgpuVersion;
+use crate::parser::{parse_attrs, private_path, AttrBinding, BindingOption};
+
+pub fn derive_wrld_bind_group(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_bind_group(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+const BINDING_KINDS: [&str; 4] = ["uniform", "storage", "texture", "sampler"];
+
+/// Binding of a field, with the tokens of its `wgpu::BindingType`.
+struct Binding {
+    binding: u32,
+    field: proc_macro2::Ident,
+    visibility: proc_macro2::TokenStream,
+    ty: proc_macro2::TokenStream
+}
+
+/// `wgpu::ShaderStages` of `"vertex | fragment"`.
+pub fn shader_stages(stages: &syn::LitStr, wgpu: &proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let mut tokens = quote::quote! { #wgpu::ShaderStages::NONE };
+
+    for stage in stages.value().split('|').map(str::trim).filter(|stage| !stage.is_empty()) {
+        let stage = match stage {
+            "vertex" => quote::quote! { VERTEX },
+            "fragment" => quote::quote! { FRAGMENT },
+            "compute" => quote::quote! { COMPUTE },
+            _ => return Err(syn::Error::new(
+                stages.span(),
+                format!("Unknown shader stage `{}`, expected vertex, fragment or compute", stage)
+            ))
+        };
+
+        tokens = quote::quote! { #tokens.union(#wgpu::ShaderStages::#stage) };
+    }
+
+    Ok(tokens)
+}
+
+fn str_option<'a>(key: &syn::Ident, value: &'a BindingOption) -> syn::Result<&'a syn::LitStr> {
+    match value {
+        BindingOption::Str(lit) => Ok(lit),
+        _ => Err(syn::Error::new(key.span(), format!("Expected `{} = \"...\"`", key)))
+    }
+}
+
+fn flag_option(key: &syn::Ident, value: &BindingOption) -> syn::Result<()> {
+    match value {
+        BindingOption::Flag => Ok(()),
+        _ => Err(syn::Error::new(key.span(), format!("`{}` does not take a value", key)))
+    }
+}
+
+/// `wgpu::BindingType` of a binding attribute, the options that are not related to the kind are errors.
+fn binding_type(
+    kind: &str,
+    attr: &AttrBinding,
+    wgpu: &proc_macro2::TokenStream
+) -> syn::Result<(proc_macro2::TokenStream, Option<syn::LitStr>)> {
+    let mut visibility = None;
+    let mut dynamic = false;
+    let mut read_only = false;
+    let mut min_binding_size = quote::quote! { ::core::option::Option::None };
+    let mut sample_type = quote::quote! { #wgpu::TextureSampleType::Float { filterable: true } };
+    let mut dimension = quote::quote! { #wgpu::TextureViewDimension::D2 };
+    let mut multisampled = false;
+    let mut sampler = quote::quote! { Filtering };
+
+    for (key, value) in &attr.options {
+        match (kind, key.to_string().as_str()) {
+            (_, "visibility") => visibility = Some(str_option(key, value)?.clone()),
+            ("uniform" | "storage", "dynamic") => {
+                flag_option(key, value)?;
+                dynamic = true;
+            },
+            ("storage", "read_only") => {
+                flag_option(key, value)?;
+                read_only = true;
+            },
+            ("uniform" | "storage", "min_binding_size") => match value {
+                BindingOption::Path(path) => min_binding_size = quote::quote! { #path::MIN_BINDING_SIZE },
+                _ => return Err(syn::Error::new(key.span(), "Expected `min_binding_size = <Uniform or Storage type>`"))
+            },
+            ("texture", "sample_type") => {
+                let lit = str_option(key, value)?;
+
+                sample_type = match lit.value().as_str() {
+                    "float" => quote::quote! { #wgpu::TextureSampleType::Float { filterable: true } },
+                    "unfilterable_float" => quote::quote! { #wgpu::TextureSampleType::Float { filterable: false } },
+                    "depth" => quote::quote! { #wgpu::TextureSampleType::Depth },
+                    "sint" => quote::quote! { #wgpu::TextureSampleType::Sint },
+                    "uint" => quote::quote! { #wgpu::TextureSampleType::Uint },
+                    other => return Err(syn::Error::new(
+                        lit.span(),
+                        format!("Unknown sample type `{}`, expected float, unfilterable_float, depth, sint or uint", other)
+                    ))
+                };
+            },
+            ("texture", "dimension") => {
+                let lit = str_option(key, value)?;
+
+                dimension = match lit.value().as_str() {
+                    "1d" => quote::quote! { #wgpu::TextureViewDimension::D1 },
+                    "2d" => quote::quote! { #wgpu::TextureViewDimension::D2 },
+                    "2d_array" => quote::quote! { #wgpu::TextureViewDimension::D2Array },
+                    "cube" => quote::quote! { #wgpu::TextureViewDimension::Cube },
+                    "cube_array" => quote::quote! { #wgpu::TextureViewDimension::CubeArray },
+                    "3d" => quote::quote! { #wgpu::TextureViewDimension::D3 },
+                    other => return Err(syn::Error::new(
+                        lit.span(),
+                        format!("Unknown texture dimension `{}`, expected 1d, 2d, 2d_array, cube, cube_array or 3d", other)
+                    ))
+                };
+            },
+            ("texture", "multisampled") => {
+                flag_option(key, value)?;
+                multisampled = true;
+            },
+            ("sampler", "filtering" | "non_filtering" | "comparison") => {
+                flag_option(key, value)?;
+
+                sampler = match key.to_string().as_str() {
+                    "filtering" => quote::quote! { Filtering },
+                    "non_filtering" => quote::quote! { NonFiltering },
+                    _ => quote::quote! { Comparison }
+                };
+            },
+            _ => return Err(syn::Error::new(key.span(), format!("Unknown option `{}` for #[{}]", key, kind)))
+        }
+    }
+
+    let ty = match kind {
+        "uniform" | "storage" => {
+            let buffer_type = if kind == "uniform" {
+                quote::quote! { #wgpu::BufferBindingType::Uniform }
+            } else {
+                quote::quote! { #wgpu::BufferBindingType::Storage { read_only: #read_only } }
+            };
+
+            quote::quote! {
+                #wgpu::BindingType::Buffer {
+                    ty: #buffer_type,
+                    has_dynamic_offset: #dynamic,
+                    min_binding_size: #min_binding_size
+                }
+            }
+        },
+        "texture" => quote::quote! {
+            #wgpu::BindingType::Texture {
+                sample_type: #sample_type,
+                view_dimension: #dimension,
+                multisampled: #multisampled
+            }
+        },
+        _ => quote::quote! {
+            #wgpu::BindingType::Sampler(#wgpu::SamplerBindingType::#sampler)
+        }
+    };
+
+    Ok((ty, visibility))
+}
+
+fn expand_wrld_bind_group(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, generics, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::BindGroup")?;
+
+    // The bind group entries need the wgpu resource types, wrld only has the wgpu 0.13 types.
+    if WgpuVersion::selected() == WgpuVersion::V0_13 {
+        return Err(syn::Error::new(ident.span(), "wrld::BindGroup is not available in wgpu 0.13, it require wgpu 0.19 (`wgpu-0_19` feature of wrld)"))
+    }
+
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let wrld = get_wrld_attrs(&attrs, "BindGroup", &["wgpu", "visibility"], &mut errors);
+    let wgpu = wrld.wgpu_path();
+
+    let default_visibility = match &wrld.visibility {
+        Some(stages) => shader_stages(stages, &wgpu).unwrap_or_else(|e| {
+            errors.push(e);
+            proc_macro2::TokenStream::new()
+        }),
+        None => quote::quote! { #wgpu::ShaderStages::VERTEX_FRAGMENT }
+    };
+
+    let mut bindings: Vec<Binding> = Vec::new();
+
+    for field in fields {
+        let Some(name) = field.ident.clone() else { continue };
+        let mut field_bindings: Vec<(String, syn::Attribute)> = Vec::new();
+
+        parse_attrs(&field.attrs, Box::new(|attr| {
+            let kind = attr.segment.ident.to_string();
+
+            if BINDING_KINDS.contains(&kind.as_str()) {
+                field_bindings.push((kind, attr.attribute.clone()));
+            }
+        }));
+
+        if field_bindings.len() > 1 {
+            errors.push(syn::Error::new_spanned(&field_bindings[1].1, "A field can only have one binding"));
+        }
+
+        let Some((kind, attribute)) = field_bindings.into_iter().next() else { continue };
+
+        let binding = attribute.parse_args::<AttrBinding>().and_then(|attr| {
+            let (ty, visibility) = binding_type(&kind, &attr, &wgpu)?;
+
+            let visibility = match visibility {
+                Some(stages) => shader_stages(&stages, &wgpu)?,
+                None => default_visibility.clone()
+            };
+
+            Ok(Binding { binding: attr.binding, field: name.clone(), visibility, ty })
+        });
+
+        match binding {
+            Ok(binding) => {
+                if let Some(other) = bindings.iter().find(|b| b.binding == binding.binding) {
+                    errors.push(syn::Error::new_spanned(
+                        &attribute,
+                        format!("Binding {} is already used by `{}`", binding.binding, other.field)
+                    ));
+                }
+
+                bindings.push(binding);
+            },
+            Err(e) => errors.push(e)
+        }
+    }
+
+    combine_errors(errors)?;
+
+    let private = private_path();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let count = bindings.len();
+
+    let layout_entries = bindings.iter().map(|Binding { binding, visibility, ty, .. }| quote::quote! {
+        #wgpu::BindGroupLayoutEntry {
+            binding: #binding,
+            visibility: #visibility,
+            ty: #ty,
+            count: ::core::option::Option::None
+        }
+    });
+
+    let entries = bindings.iter().map(|Binding { binding, field, .. }| quote::quote! {
+        #wgpu::BindGroupEntry {
+            binding: #binding,
+            resource: #private::AsBindingResource::as_binding_resource(&self.#field)
+        }
+    });
+
+    Ok(quote::quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Entries of the bind group layout, one per binding field.
+            pub const LAYOUT_ENTRIES: &'static [#wgpu::BindGroupLayoutEntry] = &[#(#layout_entries),*];
+
+            /// Bind group layout descriptor of the bindings.
+            pub fn layout_descriptor(label: ::core::option::Option<&str>) -> #wgpu::BindGroupLayoutDescriptor<'_> {
+                #wgpu::BindGroupLayoutDescriptor {
+                    label,
+                    entries: Self::LAYOUT_ENTRIES
+                }
+            }
+
+            /// Bind group entries built from the fields, in the order of `LAYOUT_ENTRIES`.
+            pub fn entries(&self) -> [#wgpu::BindGroupEntry<'_>; #count] {
+                [#(#entries),*]
+            }
+        }
+    })
+}
@@ -0,0 +1,181 @@
+use phf::phf_map;
+
+use crate::format::{ScalarKind, VertexFormat};
+
+#[derive(Copy, Clone, Debug)]
+pub struct TypeToWGPU {
+    pub offset: u64,
+    pub ty: VertexFormat,
+}
+
+#[derive(Copy, Clone)]
+pub struct WGPUData {
+    pub wgpu_type: TypeToWGPU,
+    pub shader_location: u32
+}
+
+static TYPE_MAPPER : phf::Map<&'static str, TypeToWGPU> = phf_map! {
+    "u32" => TypeToWGPU { offset: VertexFormat::Uint32.size(), ty: VertexFormat::Uint32 },
+    "f32" => TypeToWGPU { offset: VertexFormat::Float32.size(), ty: VertexFormat::Float32 },
+    "s32" => TypeToWGPU { offset: VertexFormat::Sint32.size(), ty: VertexFormat::Sint32 },
+    "f64" => TypeToWGPU { offset: VertexFormat::Float64.size(), ty: VertexFormat::Float64 },
+    "u8x2" => TypeToWGPU { offset: VertexFormat::Uint8x2.size(), ty: VertexFormat::Uint8x2 },
+    "u8x4" => TypeToWGPU { offset: VertexFormat::Uint8x4.size(), ty: VertexFormat::Uint8x4 },
+    "s8x2" => TypeToWGPU { offset: VertexFormat::Sint8x2.size(), ty: VertexFormat::Sint8x2 },
+    "s8x4" => TypeToWGPU { offset: VertexFormat::Sint8x4.size(), ty: VertexFormat::Sint8x4 },
+    "un8x2" => TypeToWGPU { offset: VertexFormat::Unorm8x2.size(), ty: VertexFormat::Unorm8x2 },
+    "un8x4" => TypeToWGPU { offset: VertexFormat::Unorm8x4.size(), ty: VertexFormat::Unorm8x4 },
+    "sn8x2" => TypeToWGPU { offset: VertexFormat::Snorm8x2.size(), ty: VertexFormat::Snorm8x2 },
+    "sn8x4" => TypeToWGPU { offset: VertexFormat::Snorm8x4.size(), ty: VertexFormat::Snorm8x4 },
+    "u16x2" => TypeToWGPU { offset: VertexFormat::Uint16x2.size(), ty: VertexFormat::Uint16x2 },
+    "u16x4" => TypeToWGPU { offset: VertexFormat::Uint16x4.size(), ty: VertexFormat::Uint16x4 },
+    "s16x2" => TypeToWGPU { offset: VertexFormat::Sint16x2.size(), ty: VertexFormat::Sint16x2 },
+    "s16x4" => TypeToWGPU { offset: VertexFormat::Sint16x4.size(), ty: VertexFormat::Sint16x4 },
+    "un16x2" => TypeToWGPU { offset: VertexFormat::Unorm16x2.size(), ty: VertexFormat::Unorm16x2 },
+    "un16x4" => TypeToWGPU { offset: VertexFormat::Unorm16x4.size(), ty: VertexFormat::Unorm16x4 },
+    "sn16x2" => TypeToWGPU { offset: VertexFormat::Snorm16x2.size(), ty: VertexFormat::Snorm16x2 },
+    "sn16x4" => TypeToWGPU { offset: VertexFormat::Snorm16x4.size(), ty: VertexFormat::Snorm16x4 },
+    "f16x2" => TypeToWGPU { offset: VertexFormat::Float16x2.size(), ty: VertexFormat::Float16x2 },
+    "f16x4" => TypeToWGPU { offset: VertexFormat::Float16x4.size(), ty: VertexFormat::Float16x4 },
+    "f32x2" => TypeToWGPU { offset: VertexFormat::Float32x2.size(), ty: VertexFormat::Float32x2 },
+    "f32x3" => TypeToWGPU { offset: VertexFormat::Float32x3.size(), ty: VertexFormat::Float32x3 },
+    "f32x4" => TypeToWGPU { offset: VertexFormat::Float32x4.size(), ty: VertexFormat::Float32x4 },
+    "u32x2" => TypeToWGPU { offset: VertexFormat::Uint32x2.size(), ty: VertexFormat::Uint32x2 },
+    "u32x3" => TypeToWGPU { offset: VertexFormat::Uint32x3.size(), ty: VertexFormat::Uint32x3 },
+    "u32x4" => TypeToWGPU { offset: VertexFormat::Uint32x4.size(), ty: VertexFormat::Uint32x4 },
+    "s32x2" => TypeToWGPU { offset: VertexFormat::Sint32x2.size(), ty: VertexFormat::Sint32x2 },
+    "s32x3" => TypeToWGPU { offset: VertexFormat::Sint32x3.size(), ty: VertexFormat::Sint32x3 },
+    "s32x4" => TypeToWGPU { offset: VertexFormat::Sint32x4.size(), ty: VertexFormat::Sint32x4 },
+    "f64x2" => TypeToWGPU { offset: VertexFormat::Float64x2.size(), ty: VertexFormat::Float64x2 },
+    "f64x3" => TypeToWGPU { offset: VertexFormat::Float64x3.size(), ty: VertexFormat::Float64x3 },
+    "f64x4" => TypeToWGPU { offset: VertexFormat::Float64x4.size(), ty: VertexFormat::Float64x4 },
+    "u8" => TypeToWGPU { offset: VertexFormat::Uint8.size(), ty: VertexFormat::Uint8 },
+    "s8" => TypeToWGPU { offset: VertexFormat::Sint8.size(), ty: VertexFormat::Sint8 },
+    "un8" => TypeToWGPU { offset: VertexFormat::Unorm8.size(), ty: VertexFormat::Unorm8 },
+    "sn8" => TypeToWGPU { offset: VertexFormat::Snorm8.size(), ty: VertexFormat::Snorm8 },
+    "u16" => TypeToWGPU { offset: VertexFormat::Uint16.size(), ty: VertexFormat::Uint16 },
+    "s16" => TypeToWGPU { offset: VertexFormat::Sint16.size(), ty: VertexFormat::Sint16 },
+    "un16" => TypeToWGPU { offset: VertexFormat::Unorm16.size(), ty: VertexFormat::Unorm16 },
+    "sn16" => TypeToWGPU { offset: VertexFormat::Snorm16.size(), ty: VertexFormat::Snorm16 },
+    "f16" => TypeToWGPU { offset: VertexFormat::Float16.size(), ty: VertexFormat::Float16 },
+    "un10_10_10_2" => TypeToWGPU { offset: VertexFormat::Unorm10_10_10_2.size(), ty: VertexFormat::Unorm10_10_10_2 },
+    "un8x4bgra" => TypeToWGPU { offset: VertexFormat::Unorm8x4Bgra.size(), ty: VertexFormat::Unorm8x4Bgra }
+};
+
+fn get_type(name: &str) -> Result<TypeToWGPU, String> {
+    if let Some(typ) = TYPE_MAPPER.get(name) {
+        typ.ty.check_available()?;
+        return Ok(*typ)
+    }
+
+    Err(format!("Cannot get type for {:?}", name))
+}
+
+pub fn get_allowed_type(name: &str) -> std::vec::Vec<&str> {
+    let mut vec: std::vec::Vec::<&str> = std::vec::Vec::new();
+    for i in TYPE_MAPPER.keys() {
+        let row = i.strip_prefix(name).and_then(|r| r.strip_prefix('x'));
+
+        if matches!(row.map(|r| r.as_bytes()), Some([b'2'..=b'4'])) {
+            vec.push(i);
+        }
+    }
+    vec.sort_unstable();
+    vec
+}
+
+/// Find the attribute name matching a rust field type, the same name as the explicit format attribute.
+///
+/// Returns the attribute name and, for matrices, the row type (`[[f32; 4]; 4]` gives `("mat4x4", Some("f32"))`).
+/// Integer types are never inferred as normalized formats, use the explicit attribute for that.
+pub fn infer_type_name(scalar: &str, dims: &[usize]) -> Option<(String, Option<String>)> {
+    let prefix = match scalar {
+        "u8" => "u8",
+        "i8" => "s8",
+        "u16" => "u16",
+        "i16" => "s16",
+        "u32" => "u32",
+        "i32" => "s32",
+        "f32" => "f32",
+        "f64" => "f64",
+        _ => return None
+    };
+
+    let inferred = match dims {
+        [] => (prefix.to_string(), None),
+        [len] => (format!("{}x{}", prefix, len), None),
+        [column, row] => (format!("mat{}x{}", column, row), Some(prefix.to_string())),
+        _ => return None
+    };
+
+    let valid = match &inferred {
+        (name, None) => has_type(name),
+        (name, Some(ty)) => is_mat_type(name) && has_type(&format!("{}x{}", ty, dims[1]))
+    };
+
+    valid.then_some(inferred)
+}
+
+/// Find the attribute name and the rust type of a WGSL scalar or vector shader input, `vec3<f32>` gives `("f32x3", "[f32; 3]")`.
+///
+/// Integer inputs are never given a normalized or smaller format, the field have the size of the shader type.
+#[cfg_attr(not(feature = "shader"), allow(dead_code))]
+pub fn wgsl_type_name(kind: ScalarKind, width: u8, size: Option<u8>) -> Option<(String, String)> {
+    let (prefix, scalar) = match (kind, width) {
+        (ScalarKind::Float, 4) => ("f32", "f32"),
+        (ScalarKind::Float, 8) => ("f64", "f64"),
+        (ScalarKind::Uint, 4) => ("u32", "u32"),
+        (ScalarKind::Sint, 4) => ("s32", "i32"),
+        _ => return None
+    };
+
+    let named = match size {
+        None => (prefix.to_string(), scalar.to_string()),
+        Some(size) => (format!("{}x{}", prefix, size), format!("[{}; {}]", scalar, size))
+    };
+
+    has_type(&named.0).then_some(named)
+}
+
+pub fn is_mat_type(name: &str) -> bool {
+    let dims = name.strip_prefix("mat").map(|d| d.as_bytes());
+
+    matches!(dims, Some([b'2'..=b'4', b'x', b'2'..=b'4']))
+}
+
+pub fn convert_mat_type_to_wgou(name: &str, shader_location: u32, ty: &str) -> Result<std::vec::Vec<WGPUData>, String> {
+    if !is_mat_type(name) {
+        return Err(format!("{} is not a matrix attribute", name));
+    }
+
+    let mut wgpu_vec: Vec<WGPUData> = std::vec::Vec::new();
+    let mut row = ty.to_string();
+    row.push_str(&name[name.len() - 2..name.len()]);
+
+    let column: u32 = name[name.len() - 3..name.len() - 2].parse().map_err(|_| format!("Invalid matrix {}", name))?;
+    let mut final_ty = convert_type_to_wgpu(&row, shader_location).map_err(|_| {
+
+        let allowed_types = get_allowed_type(ty)
+            .join(" or ")
+            .replace(ty, format!("mat{:?}", column).as_str());
+
+        format!("Matrix {} cannot be use with {} ! Available matrix are {} for {}", name, ty, allowed_types, ty)
+    })?;
+
+    for i in 0..column {
+        final_ty.shader_location += if i == 0 { 0 } else { 1 };
+        wgpu_vec.push(final_ty);
+    }
+
+    Ok(wgpu_vec)
+}
+
+pub fn convert_type_to_wgpu(name: &str, shader_location: u32) -> Result<WGPUData, String> {
+    let wgpu_type = get_type(name)?;
+
+    Ok(WGPUData { wgpu_type, shader_location })
+}
+
+pub fn has_type(name: &str) -> bool {
+    TYPE_MAPPER.contains_key(name)
+}
\ No newline at end of file
@@ -0,0 +1,246 @@
+//! Vertex format and step mode model of the macro.
+//!
+//! The macro does not depend on wgpu, formats are emitted by name as
+//! `wgpu::VertexFormat::<Name>` in the generated code and resolved against the wgpu of the user.
+
+/// wgpu versions the generated code can target, selected with the `wgpu-*` features.
+///
+/// When multiple features are enabled, the newest version win.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WgpuVersion {
+    V0_13,
+    V0_19,
+    Latest
+}
+
+impl WgpuVersion {
+    /// Version selected by the enabled features, the latest one if none is enabled.
+    pub const fn selected() -> Self {
+        if cfg!(feature = "wgpu-latest") {
+            WgpuVersion::Latest
+        } else if cfg!(feature = "wgpu-0_19") {
+            WgpuVersion::V0_19
+        } else if cfg!(feature = "wgpu-0_13") {
+            WgpuVersion::V0_13
+        } else {
+            WgpuVersion::Latest
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            WgpuVersion::V0_13 => "0.13",
+            WgpuVersion::V0_19 => "0.19",
+            WgpuVersion::Latest => "30"
+        }
+    }
+
+    pub const fn feature(self) -> &'static str {
+        match self {
+            WgpuVersion::V0_13 => "wgpu-0_13",
+            WgpuVersion::V0_19 => "wgpu-0_19",
+            WgpuVersion::Latest => "wgpu-latest"
+        }
+    }
+}
+
+macro_rules! vertex_formats {
+    ($($format:ident => ($size:expr, $since:ident, $wgsl:literal)),* $(,)?) => {
+        /// Mirror of `wgpu::VertexFormat`, variants have the same name.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub enum VertexFormat {
+            $($format),*
+        }
+
+        impl VertexFormat {
+            /// Size of the format in bytes.
+            pub const fn size(self) -> u64 {
+                match self {
+                    $(VertexFormat::$format => $size),*
+                }
+            }
+
+            /// Name of the `wgpu::VertexFormat` variant.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(VertexFormat::$format => stringify!($format)),*
+                }
+            }
+
+            /// WGSL type of the shader input of this format.
+            pub const fn wgsl_type(self) -> &'static str {
+                match self {
+                    $(VertexFormat::$format => $wgsl),*
+                }
+            }
+
+            /// First wgpu version having this format.
+            pub const fn since(self) -> WgpuVersion {
+                match self {
+                    $(VertexFormat::$format => WgpuVersion::$since),*
+                }
+            }
+        }
+    };
+}
+
+vertex_formats! {
+    Uint8x2 => (2, V0_13, "vec2<u32>"),
+    Uint8x4 => (4, V0_13, "vec4<u32>"),
+    Sint8x2 => (2, V0_13, "vec2<i32>"),
+    Sint8x4 => (4, V0_13, "vec4<i32>"),
+    Unorm8x2 => (2, V0_13, "vec2<f32>"),
+    Unorm8x4 => (4, V0_13, "vec4<f32>"),
+    Snorm8x2 => (2, V0_13, "vec2<f32>"),
+    Snorm8x4 => (4, V0_13, "vec4<f32>"),
+    Uint16x2 => (4, V0_13, "vec2<u32>"),
+    Uint16x4 => (8, V0_13, "vec4<u32>"),
+    Sint16x2 => (4, V0_13, "vec2<i32>"),
+    Sint16x4 => (8, V0_13, "vec4<i32>"),
+    Unorm16x2 => (4, V0_13, "vec2<f32>"),
+    Unorm16x4 => (8, V0_13, "vec4<f32>"),
+    Snorm16x2 => (4, V0_13, "vec2<f32>"),
+    Snorm16x4 => (8, V0_13, "vec4<f32>"),
+    Float16x2 => (4, V0_13, "vec2<f32>"),
+    Float16x4 => (8, V0_13, "vec4<f32>"),
+    Float32 => (4, V0_13, "f32"),
+    Float32x2 => (8, V0_13, "vec2<f32>"),
+    Float32x3 => (12, V0_13, "vec3<f32>"),
+    Float32x4 => (16, V0_13, "vec4<f32>"),
+    Uint32 => (4, V0_13, "u32"),
+    Uint32x2 => (8, V0_13, "vec2<u32>"),
+    Uint32x3 => (12, V0_13, "vec3<u32>"),
+    Uint32x4 => (16, V0_13, "vec4<u32>"),
+    Sint32 => (4, V0_13, "i32"),
+    Sint32x2 => (8, V0_13, "vec2<i32>"),
+    Sint32x3 => (12, V0_13, "vec3<i32>"),
+    Sint32x4 => (16, V0_13, "vec4<i32>"),
+    Float64 => (8, V0_13, "f64"),
+    Float64x2 => (16, V0_13, "vec2<f64>"),
+    Float64x3 => (24, V0_13, "vec3<f64>"),
+    Float64x4 => (32, V0_13, "vec4<f64>"),
+    Uint8 => (1, Latest, "u32"),
+    Sint8 => (1, Latest, "i32"),
+    Unorm8 => (1, Latest, "f32"),
+    Snorm8 => (1, Latest, "f32"),
+    Uint16 => (2, Latest, "u32"),
+    Sint16 => (2, Latest, "i32"),
+    Unorm16 => (2, Latest, "f32"),
+    Snorm16 => (2, Latest, "f32"),
+    Float16 => (2, Latest, "f32"),
+    Unorm10_10_10_2 => (4, Latest, "vec4<f32>"),
+    Unorm8x4Bgra => (4, Latest, "vec4<f32>"),
+}
+
+/// Scalar kind of the shader input fed by a format, a float format cannot feed an integer input and the reverse.
+#[cfg_attr(not(feature = "shader"), allow(dead_code))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScalarKind {
+    Float,
+    Uint,
+    Sint
+}
+
+#[cfg_attr(not(feature = "shader"), allow(dead_code))]
+impl ScalarKind {
+    pub const fn name(self) -> &'static str {
+        match self {
+            ScalarKind::Float => "float",
+            ScalarKind::Uint => "unsigned integer",
+            ScalarKind::Sint => "signed integer"
+        }
+    }
+}
+
+impl VertexFormat {
+    /// Scalar kind of the shader input, normalized formats are read as float.
+    #[cfg_attr(not(feature = "shader"), allow(dead_code))]
+    pub fn scalar_kind(self) -> ScalarKind {
+        if self.name().starts_with("Uint") {
+            ScalarKind::Uint
+        } else if self.name().starts_with("Sint") {
+            ScalarKind::Sint
+        } else {
+            ScalarKind::Float
+        }
+    }
+
+    /// Scalar type and vector size of the WGSL type, `("f32", Some(4))` for `vec4<f32>`.
+    fn wgsl_parts(self) -> (&'static str, Option<&'static str>) {
+        let wgsl = self.wgsl_type();
+
+        match wgsl.strip_prefix("vec") {
+            Some(vector) => (&vector[2..vector.len() - 1], Some(&vector[..1])),
+            None => (wgsl, None)
+        }
+    }
+
+    /// GLSL type of the shader input of this format, `vec4`, `uvec2`, `double`, ect...
+    pub fn glsl_type(self) -> String {
+        let (scalar, size) = self.wgsl_parts();
+
+        match size {
+            Some(size) => {
+                let prefix = match scalar {
+                    "u32" => "u",
+                    "i32" => "i",
+                    "f64" => "d",
+                    _ => ""
+                };
+
+                format!("{}vec{}", prefix, size)
+            },
+            None => match scalar {
+                "u32" => "uint",
+                "i32" => "int",
+                "f64" => "double",
+                _ => "float"
+            }.to_string()
+        }
+    }
+
+    /// HLSL type of the shader input of this format, `float4`, `uint2`, `double`, ect...
+    pub fn hlsl_type(self) -> String {
+        let (scalar, size) = self.wgsl_parts();
+
+        let scalar = match scalar {
+            "u32" => "uint",
+            "i32" => "int",
+            "f64" => "double",
+            _ => "float"
+        };
+
+        format!("{}{}", scalar, size.unwrap_or(""))
+    }
+
+    /// Check that the format exist in the selected wgpu version.
+    pub fn check_available(self) -> Result<Self, String> {
+        let selected = WgpuVersion::selected();
+
+        if self.since() > selected {
+            return Err(format!(
+                "Vertex format {} is not available in wgpu {}, it require wgpu {} (`{}` feature of wrld)",
+                self.name(), selected.name(), self.since().name(), self.since().feature()
+            ));
+        }
+
+        Ok(self)
+    }
+}
+
+/// Mirror of `wgpu::VertexStepMode`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VertexStepMode {
+    Vertex,
+    Instance
+}
+
+impl VertexStepMode {
+    /// Name of the `wgpu::VertexStepMode` variant.
+    pub const fn name(self) -> &'static str {
+        match self {
+            VertexStepMode::Vertex => "Vertex",
+            VertexStepMode::Instance => "Instance"
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use crate::macros::{combine_errors, get_named_fields, get_wrld_attrs};
+use crate::memory::{Scalar, ShaderType};
+use crate::parser::{parse_attrs, AttrTarget, BindingOption};
+
+pub fn derive_wrld_fragment_output(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_fragment_output(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Color target of a field, with the tokens of its `wgpu::ColorTargetState`.
+struct Target {
+    index: u32,
+    field: proc_macro2::Ident,
+    ty: ShaderType,
+    state: proc_macro2::TokenStream
+}
+
+/// Scalar type written by the shader in a texture format, `None` for depth and stencil formats.
+fn format_scalar(format: &syn::Ident) -> Option<Scalar> {
+    let name = format.to_string();
+
+    if name.contains("Depth") || name.contains("Stencil") {
+        None
+    } else if name.contains("Uint") {
+        Some(Scalar::U32)
+    } else if name.contains("Sint") {
+        Some(Scalar::I32)
+    } else {
+        Some(Scalar::F32)
+    }
+}
+
+/// `wgpu::ColorWrites` of `"red | green | blue | alpha"`.
+fn color_writes(mask: &syn::LitStr, wgpu: &proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let mut tokens = quote::quote! { #wgpu::ColorWrites::empty() };
+
+    for channel in mask.value().split('|').map(str::trim).filter(|channel| !channel.is_empty()) {
+        let channel = match channel {
+            "red" => quote::quote! { RED },
+            "green" => quote::quote! { GREEN },
+            "blue" => quote::quote! { BLUE },
+            "alpha" => quote::quote! { ALPHA },
+            "color" => quote::quote! { COLOR },
+            "all" => quote::quote! { ALL },
+            _ => return Err(syn::Error::new(
+                mask.span(),
+                format!("Unknown color channel `{}`, expected red, green, blue, alpha, color or all", channel)
+            ))
+        };
+
+        tokens = quote::quote! { #tokens.union(#wgpu::ColorWrites::#channel) };
+    }
+
+    Ok(tokens)
+}
+
+/// `wgpu::ColorTargetState` of a target attribute, checked against the field type.
+fn target_state(attr: &AttrTarget, ty: &syn::Type, shader_type: &ShaderType, wgpu: &proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let format = &attr.format;
+
+    let Some(scalar) = format_scalar(format) else {
+        return Err(syn::Error::new(format.span(), format!("`{}` is not a color format", format)))
+    };
+
+    let (ShaderType::Scalar(output) | ShaderType::Vector(output, _)) = shader_type else {
+        unreachable!("fragment outputs are scalars or vectors")
+    };
+
+    if *output != scalar {
+        return Err(syn::Error::new_spanned(
+            ty,
+            format!("`{}` is written as `{}`, the field must be a {} or a vector of {}", format, scalar.wgsl_type(), scalar.wgsl_type(), scalar.wgsl_type())
+        ));
+    }
+
+    let mut blend = quote::quote! { ::core::option::Option::None };
+    let mut write_mask = quote::quote! { #wgpu::ColorWrites::ALL };
+
+    for (key, value) in &attr.options {
+        match (key.to_string().as_str(), value) {
+            ("blend", BindingOption::Path(path)) => {
+                if scalar != Scalar::F32 {
+                    return Err(syn::Error::new(key.span(), format!("`{}` is an integer format, it cannot be blended", format)));
+                }
+
+                let state = match path.get_ident().map(|ident| ident.to_string()).as_deref() {
+                    Some("replace") => quote::quote! { REPLACE },
+                    Some("alpha") => quote::quote! { ALPHA_BLENDING },
+                    Some("premultiplied_alpha") => quote::quote! { PREMULTIPLIED_ALPHA_BLENDING },
+                    _ => return Err(syn::Error::new_spanned(path, "Unknown blend state, expected replace, alpha or premultiplied_alpha"))
+                };
+
+                blend = quote::quote! { ::core::option::Option::Some(#wgpu::BlendState::#state) };
+            },
+            ("write_mask", BindingOption::Str(mask)) => write_mask = color_writes(mask, wgpu)?,
+            ("blend", _) => return Err(syn::Error::new(key.span(), "Expected `blend = replace | alpha | premultiplied_alpha`")),
+            ("write_mask", _) => return Err(syn::Error::new(key.span(), "Expected `write_mask = \"red | green | blue | alpha\"`")),
+            _ => return Err(syn::Error::new(key.span(), format!("Unknown option `{}` for #[target]", key)))
+        }
+    }
+
+    let format = quote::quote_spanned! {format.span()=> #wgpu::TextureFormat::#format };
+
+    Ok(quote::quote! {
+        #wgpu::ColorTargetState {
+            format: #format,
+            blend: #blend,
+            write_mask: #write_mask
+        }
+    })
+}
+
+fn expand_wrld_fragment_output(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, generics, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::FragmentOutput")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let wrld = get_wrld_attrs(&attrs, "FragmentOutput", &["wgpu"], &mut errors);
+    let wgpu = wrld.wgpu_path();
+    let mut targets: Vec<Target> = Vec::new();
+
+    for field in fields {
+        let Some(name) = field.ident.clone() else { continue };
+        let mut field_targets: Vec<syn::Attribute> = Vec::new();
+
+        parse_attrs(&field.attrs, Box::new(|attr| {
+            if attr.segment.ident == "target" {
+                field_targets.push(attr.attribute.clone());
+            }
+        }));
+
+        if field_targets.len() > 1 {
+            errors.push(syn::Error::new_spanned(&field_targets[1], "A field can only have one color target"));
+        }
+
+        let Some(attribute) = field_targets.into_iter().next() else {
+            errors.push(syn::Error::new(name.span(), format!("Missing #[target(<index>, <format>)] on `{}`", name)));
+            continue
+        };
+
+        let ty = match ShaderType::parse(&field.ty) {
+            Some(ty @ (ShaderType::Scalar(_) | ShaderType::Vector(_, _))) => ty,
+            _ => {
+                errors.push(syn::Error::new_spanned(
+                    &field.ty,
+                    "This type cannot be a fragment output, expected f32, u32, i32 or vectors of them ([T; 2..=4])"
+                ));
+                continue
+            }
+        };
+
+        let target = attribute.parse_args::<AttrTarget>().and_then(|attr| {
+            let state = target_state(&attr, &field.ty, &ty, &wgpu)?;
+
+            Ok(Target { index: attr.index, field: name.clone(), ty, state })
+        });
+
+        match target {
+            Ok(target) => {
+                if let Some(other) = targets.iter().find(|t| t.index == target.index) {
+                    errors.push(syn::Error::new_spanned(
+                        &attribute,
+                        format!("Color target {} is already used by `{}`", target.index, other.field)
+                    ));
+                }
+
+                targets.push(target);
+            },
+            Err(e) => errors.push(e)
+        }
+    }
+
+    combine_errors(errors)?;
+
+    let mut wgsl = format!("struct {} {{\n", ident);
+
+    for target in &targets {
+        let name = target.field.to_string();
+        let name = name.trim_start_matches("r#");
+
+        wgsl.push_str(&format!("    @location({}) {}: {},\n", target.index, name, target.ty.wgsl_type()));
+    }
+
+    wgsl.push_str("}\n");
+
+    // Indices without field are holes of the array.
+    let count = targets.iter().map(|t| t.index as usize + 1).max().unwrap_or(0);
+
+    let states = (0..count as u32).map(|index| match targets.iter().find(|t| t.index == index) {
+        Some(Target { state, .. }) => quote::quote! { ::core::option::Option::Some(#state) },
+        None => quote::quote! { ::core::option::Option::None }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote::quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Color targets of the fragment state, indexed by location.
+            pub const TARGETS: [::core::option::Option<#wgpu::ColorTargetState>; #count] = [#(#states),*];
+
+            /// WGSL structure returned by the fragment shader.
+            pub const WGSL: &'static str = #wgsl;
+        }
+    })
+}
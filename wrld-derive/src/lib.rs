@@ -0,0 +1,1224 @@
+// MIT License
+
+// Copyright (c) 2022 BrindilleDeLaForet
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! ## Description
+//! 
+//! Derive macros of [wrld](https://docs.rs/wrld).
+//! 
+//! This crate is not meant to be used directly, use the wrld crate that re-export the derive macros with the runtime types they need.
+use proc_macro::{TokenStream};
+
+mod converter;
+mod format;
+mod parser;
+mod macros;
+mod shader;
+mod memory;
+mod uniform;
+mod storage;
+mod bind_group;
+mod push_constant;
+mod varyings;
+mod fragment_output;
+mod pipeline_constants;
+
+/// Desc is a proc derive macro that allow you to describe a structure as a description to pass to a renderpipeline.
+///
+/// `wgpu` is `::wrld::__private::wgpu` in the generated code.
+/// 
+/// ## Example
+/// ```
+/// use wrld::Desc;
+///
+/// #[repr(C)]
+/// #[derive(Desc)]
+/// struct Test {
+///     #[f32x3(0)] position: [f32; 3],
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// ```
+/// into
+/// ```ignore
+/// impl wrld::VertexLayout for Test {
+///     const ATTRIBUTES: &'static [wgpu::VertexAttribute] = &[
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Test, position) as wgpu::BufferAddress + 0u64), // 0
+///             format: wgpu::VertexFormat::Float32x3,
+///             shader_location: 0u32,
+///         },
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Test, color) as wgpu::BufferAddress + 0u64), // 12
+///             format: wgpu::VertexFormat::Float32x4,
+///             shader_location: 1u32,
+///         },
+///     ];
+///     const ARRAY_STRIDE: wgpu::BufferAddress = ::core::mem::size_of::<Test>() as wgpu::BufferAddress; // 28
+///     const STEP_MODE: wgpu::VertexStepMode = wgpu::VertexStepMode::Vertex;
+/// }
+///
+/// impl Test {
+///     pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+///         <Self as wrld::VertexLayout>::LAYOUT
+///     }
+/// }
+/// ```
+/// 
+/// ## VertexLayout
+/// 
+/// The layout is provided by the `wrld::VertexLayout` trait, `desc()` is a shortcut for `VertexLayout::LAYOUT`.
+/// The trait allow to write generic code over described structures :
+/// ```
+/// fn pipeline_buffers<V: wrld::VertexLayout, I: wrld::VertexLayout>() -> [wrld::wgpu::VertexBufferLayout<'static>; 2] {
+///     [V::LAYOUT, I::LAYOUT]
+/// }
+/// ```
+/// 
+/// ## Offsets and array stride
+/// 
+/// Offsets are the real position of each field inside the structure (`offset_of!`) and `array_stride` is the real size of the structure (`size_of`).
+/// Padding inserted by the compiler and fields without attribute are taken into account, so the layout always match the bytes of the structure.
+/// 
+/// ## Field size check
+/// 
+/// The size of each annotated field type is checked at compile time against the size of its format.
+/// ```compile_fail
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x4(0)] color: [f32; 3]
+/// }
+/// ```
+/// Will throw an error on the `color` field :
+/// 
+/// "wrld: the type of field `color` does not have the size of #[f32x4] (16 bytes)"
+/// 
+/// ## Inferred format
+/// 
+/// `#[vertex(location = N)]` pick the format from the field type, `[f32; 3]` is `f32x3`, `u32` is `u32`, `[[f32; 4]; 4]` is `mat4x4(f32, N)`, ect...
+/// 
+/// Integer types are never inferred as normalized formats, use the explicit format attribute for that.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[vertex(location = 0)] position: [f32; 3],
+///     #[vertex(location = 1)] id: u32,
+///     #[un8x4(2)] color: [u8; 4]
+/// }
+/// ```
+/// 
+/// ## wgpu version
+/// 
+/// The formats available depend on the wgpu version selected with the features of wrld (`wgpu-latest` by default, `wgpu-0_19` or `wgpu-0_13`).
+/// Single component formats (`u8`, `s8`, `un8`, `sn8`, `u16`, `s16`, `un16`, `sn16`, `f16`), `un10_10_10_2` and `un8x4bgra` require `wgpu-latest`.
+/// Using them with an older version is a compile error :
+/// 
+/// "Vertex format Uint8 is not available in wgpu 0.19, it require wgpu 30 (`wgpu-latest` feature of wrld)"
+/// 
+/// ## Automatic shader location
+/// 
+/// With `#[wrld(auto_location)]` on the structure, the location can be omitted from the attributes.
+/// Locations are then assigned in field order starting from 0, or from the given base with `#[wrld(auto_location = N)]`.
+/// Matrices take one location per column and an explicit location restart the numbering from it.
+/// 
+/// Every annotated field also get a `<FIELD>_LOCATION` associated const holding its (first) shader location.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::DescInstance)]
+/// #[wrld(auto_location = 5)]
+/// struct Instance {
+///     #[mat4x4(f32)] transform: [[f32; 4]; 4],
+///     #[vertex] color: [f32; 4]
+/// }
+/// 
+/// assert_eq!(Instance::TRANSFORM_LOCATION, 5);
+/// assert_eq!(Instance::COLOR_LOCATION, 9);
+/// ```
+/// 
+/// ## WGSL vertex input
+/// 
+/// The `WGSL` associated const hold the WGSL structure matching the layout, with the name of the rust structure and fields.
+/// Each format get its WGSL type (`un8x4` is `vec4<f32>`, `u32x2` is `vec2<u32>`, ect...) and matrices are written one column per location.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x3(0)] position: [f32; 3],
+///     #[un8x4(1)] color: [u8; 4],
+///     #[mat2x2(f32, 2)] transform: [[f32; 2]; 2]
+/// }
+/// 
+/// assert_eq!(Vertex::WGSL, "struct Vertex {
+///     @location(0) position: vec3<f32>,
+///     @location(1) color: vec4<f32>,
+///     @location(2) transform_0: vec2<f32>,
+///     @location(3) transform_1: vec2<f32>,
+/// }
+/// ");
+/// ```
+/// 
+/// ## GLSL and HLSL vertex input
+/// 
+/// The same way, the `GLSL` associated const hold the `layout(location = N) in` declarations of the layout
+/// and the `HLSL` associated const hold the input structure, with a `TEXCOORD<location>` semantic per input.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x3(0)] position: [f32; 3],
+///     #[u8x4(1)] color: [u8; 4]
+/// }
+/// 
+/// assert_eq!(Vertex::GLSL, "layout(location = 0) in vec3 position;
+/// layout(location = 1) in uvec4 color;
+/// ");
+/// 
+/// assert_eq!(Vertex::HLSL, "struct Vertex {
+///     float3 position : TEXCOORD0;
+///     uint4 color : TEXCOORD1;
+/// };
+/// ");
+/// ```
+/// 
+/// ## Shader validation
+/// 
+/// With the `shader` feature of wrld, `#[wrld(shader = "shaders/mesh.wgsl", entry = "vs_main")]` parse the WGSL shader with naga at compile time
+/// and check the structure against the vertex entry point. The path is relative to the crate root.
+/// 
+/// - every `@location` input of the entry point need an attribute
+/// - every attribute need to be read by the entry point
+/// - the format need to have the same scalar kind as the input (a `u8x4` cannot feed a `vec4<f32>`)
+/// 
+/// When the entry point read multiple buffers, `input = "<argument>"` restrict the check to one argument of the entry point.
+/// ```ignore
+/// // @vertex fn vs_main(vertex: VertexInput, instance: InstanceInput) -> ...
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// #[wrld(shader = "shaders/mesh.wgsl", entry = "vs_main", input = "vertex")]
+/// struct Vertex {
+///     #[f32x3(0)] position: [f32; 3],
+///     #[u8x4(1)] color: [u8; 4]
+/// }
+/// ```
+/// Will throw an error on the `color` field if the shader read it as `vec4<f32>` :
+/// 
+/// "`color` has the unsigned integer format Uint8x4 but the shader input `color` at location 1 is float"
+/// 
+/// ## Crate path
+/// 
+/// The generated code use wgpu and bytemuck from `::wrld::__private`. When wgpu or bytemuck is re-exported by another crate,
+/// the paths can be changed with `#[wrld(wgpu = "...", bytemuck = "...")]`. It must be the same wgpu as the one of wrld.
+/// ```
+/// mod engine {
+///     pub use wrld::wgpu;
+/// }
+/// 
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// #[wrld(wgpu = "crate::engine::wgpu")]
+/// struct Vertex {
+///     #[f32x2(0)] position: [f32; 2]
+/// }
+/// # fn main() {}
+/// ```
+/// 
+/// ## Matrice attributes
+/// 
+/// Matrices attributes are kind of special, because matrices are the only attributes that can take multiple location.
+/// 
+/// Matrices need two argument :
+/// - The type of the matrice (u8, f32, f64, ect...)
+/// - And the starting location
+/// 
+/// Matrices dimension start from 2x2 to 4x4
+/// 
+/// ### Example
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Actor {
+///     #[mat4x2(u8, 0)] transform: [[u8; 2]; 4]
+/// }
+/// ```
+/// Will result to
+/// ```ignore
+/// impl wrld::VertexLayout for Actor {
+///     const ATTRIBUTES: &'static [wgpu::VertexAttribute] = &[
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Actor, transform) as wgpu::BufferAddress + 0u64),
+///             format: wgpu::VertexFormat::Uint8x2,
+///             shader_location: 0u32,
+///         },
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Actor, transform) as wgpu::BufferAddress + 2u64),
+///             format: wgpu::VertexFormat::Uint8x2,
+///             shader_location: 1u32,
+///         },
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Actor, transform) as wgpu::BufferAddress + 4u64),
+///             format: wgpu::VertexFormat::Uint8x2,
+///             shader_location: 2u32,
+///         },
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Actor, transform) as wgpu::BufferAddress + 6u64),
+///             format: wgpu::VertexFormat::Uint8x2,
+///             shader_location: 3u32,
+///         },
+///     ];
+///     const ARRAY_STRIDE: wgpu::BufferAddress = ::core::mem::size_of::<Actor>() as wgpu::BufferAddress; // 8
+///     const STEP_MODE: wgpu::VertexStepMode = wgpu::VertexStepMode::Vertex;
+/// }
+/// ```
+/// So take care while using it.
+/// 
+/// Also matrix type handle only wgpu VertexFormat type for row.
+/// That does mean that matrix like that.
+/// ```compile_fail
+/// #[repr(C)]
+/// #[derive(wrld::DescInstance)]
+/// struct Vertex {
+///     #[mat4x3(u8, 0)] transform: [[u8; 3]; 4]
+/// }
+/// ```
+/// Will throw an error :
+/// 
+/// "Matrix mat4x3 cannot be use with u8 ! Available matrix are mat4x2 or mat4x4 for u8"
+/// 
+/// 
+/// ## Thing to know
+/// - Desc will not handle data transformation
+/// - Desc describe the structure as it is in memory, use BufferData to upload only the attribute fields of a chaotic structure
+#[proc_macro_derive(Desc, attributes(
+    u8, u8x2, u8x4, s8, s8x2, s8x4, un8, un8x2, un8x4, un8x4bgra, sn8, sn8x2, sn8x4,
+    u16, u16x2, u16x4, s16, s16x2, s16x4, un16, un16x2, un16x4, sn16, sn16x2, sn16x4, f16, f16x2, f16x4,
+    un10_10_10_2,
+    f32, f32x2, f32x3, f32x4,
+    u32, u32x2, u32x3, u32x4,
+    s32, s32x2, s32x3, s32x4,
+    f64, f64x2, f64x3, f64x4,
+    mat2x2, mat2x3, mat2x4,
+    mat3x2, mat3x3, mat3x4,
+    mat4x2, mat4x3, mat4x4,
+    vertex, wrld
+))]
+pub fn derive_wrld_desc(item: TokenStream) -> TokenStream { 
+    macros::derive_wrld_desc(item, format::VertexStepMode::Vertex)
+}
+
+/// DescInstance is the same as Desc. The only difference is that it change the step mode to Instance instead of Vertex
+///
+/// ## Example
+/// ```
+/// use wrld::DescInstance;
+///
+/// #[repr(C)]
+/// #[derive(DescInstance)]
+/// struct Test {
+///     #[f32x3(0)] position: [f32; 3],
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// ```
+/// into
+/// ```ignore
+/// impl wrld::VertexLayout for Test {
+///     const ATTRIBUTES: &'static [wgpu::VertexAttribute] = &[
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Test, position) as wgpu::BufferAddress + 0u64), // 0
+///             format: wgpu::VertexFormat::Float32x3,
+///             shader_location: 0u32,
+///         },
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(Test, color) as wgpu::BufferAddress + 0u64), // 12
+///             format: wgpu::VertexFormat::Float32x4,
+///             shader_location: 1u32,
+///         },
+///     ];
+///     const ARRAY_STRIDE: wgpu::BufferAddress = ::core::mem::size_of::<Test>() as wgpu::BufferAddress; // 28
+///     const STEP_MODE: wgpu::VertexStepMode = wgpu::VertexStepMode::Instance;
+/// }
+///
+/// impl Test {
+///     pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+///         <Self as wrld::VertexLayout>::LAYOUT
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DescInstance, attributes(
+    u8, u8x2, u8x4, s8, s8x2, s8x4, un8, un8x2, un8x4, un8x4bgra, sn8, sn8x2, sn8x4,
+    u16, u16x2, u16x4, s16, s16x2, s16x4, un16, un16x2, un16x4, sn16, sn16x2, sn16x4, f16, f16x2, f16x4,
+    un10_10_10_2,
+    f32, f32x2, f32x3, f32x4,
+    u32, u32x2, u32x3, u32x4,
+    s32, s32x2, s32x3, s32x4,
+    f64, f64x2, f64x3, f64x4,
+    mat2x2, mat2x3, mat2x4,
+    mat3x2, mat3x3, mat3x4,
+    mat4x2, mat4x3, mat4x4,
+    vertex, wrld
+))]
+pub fn derive_wrld_desc_instance(item: TokenStream) -> TokenStream { 
+    macros::derive_wrld_desc(item, format::VertexStepMode::Instance)
+}
+
+/// A macro to handle any type of chaotic structure.
+/// 
+/// ## What is a chaotic structure ? And what are the structure different type ?
+/// 
+/// - Chaotic structure :
+/// 
+/// structure that have attribute but the fields are not ordered (basically put everywhere and not on the top of the structure)
+/// 
+/// for example
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     some_data: String,
+///     #[f32x2(0)] position: [f32; 2],
+///     some_other_data: TypeDefinedByUser,
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// ```
+/// 
+/// is a chaotic structure because crates like bytemuck will interpret this structure like this.
+/// 
+/// ```ignore
+/// struct Vertex {
+///     some_data: String,
+///     position: [f32; 2]
+/// }
+/// ```
+/// 
+/// - Ordered structure 
+/// 
+/// is a structure that does put attribute field on the top of the structure.
+/// 
+/// for example
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x2(0)] position: [f32; 2],
+///     #[f32x4(1)] color: [f32; 4],
+///     some_data: String,
+///     some_other_data: TypeDefinedByUser
+/// }
+/// ```
+/// 
+/// is a ordered structure and bytemuck will interpret this structure like this.
+/// 
+/// ```ignore
+/// struct Vertex {
+///     position: [f32; 2],
+///     color: [f32; 4]
+/// }
+/// ```
+/// 
+/// before that macro, structure like this (chaotic structure)
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     uv: [f32; 2],
+///     #[f32x2(0)] position: [f32; 2],
+///     data: String,
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// ```
+/// Where not very well handled by wrld, because bytemuck will not look for attribute data. 
+/// Which create undefined behaviour on structure data and will not correspond to what we expect to receive.
+/// 
+/// A solution to that was to reorder structure data fields (ordered structure)
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     #[f32x2(0)] position: [f32; 2],
+///     #[f32x4(1)] color: [f32; 4],
+///     
+///     uv: [f32; 4],
+///     data: String
+/// }
+/// ```
+/// But now with BufferData this is not a problem anymore.
+/// BufferData handle any type of chaotic structure so that does mean that this structure for example
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc)]
+/// struct Vertex {
+///     uv: [f32; 4],
+///     #[f32x2(0)] position: [f32; 2],
+///     data: String,
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// ```
+/// Is handled via this macro and will have the result of what we expect it from.
+/// 
+/// ## How it's working ?
+/// 
+/// BufferData create a ordered structure from a chaotic structure. 
+/// It take any array or variable and transform it to is correponding ordered structure
+/// it also provide function and trait converter accordingly.
+/// 
+/// ## Example
+/// 
+/// Take this structure
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc, wrld::BufferData)]
+/// struct Vertex {
+///     texture: SomeTextureType,
+///     #[f32x3(0)] position: [f32; 3],
+///     message: String,
+///     #[f32x3(1)] scale: [f32; 3]
+/// }
+/// ```
+/// 
+/// This structure will result in this implementation
+/// 
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct VertexBufferData {
+///     position: [f32; 3],
+///     scale: [f32; 3]
+/// }
+///
+/// // Same attributes as Vertex, but with the offsets and the size of VertexBufferData.
+/// impl wrld::VertexLayout for VertexBufferData {
+///     const ATTRIBUTES: &'static [wgpu::VertexAttribute] = &[
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(VertexBufferData, position) as wgpu::BufferAddress + 0u64),
+///             format: wgpu::VertexFormat::Float32x3,
+///             shader_location: 0u32,
+///         },
+///         wgpu::VertexAttribute {
+///             offset: (::core::mem::offset_of!(VertexBufferData, scale) as wgpu::BufferAddress + 0u64),
+///             format: wgpu::VertexFormat::Float32x3,
+///             shader_location: 1u32,
+///         },
+///     ];
+///     const ARRAY_STRIDE: wgpu::BufferAddress = ::core::mem::size_of::<VertexBufferData>() as wgpu::BufferAddress;
+///     const STEP_MODE: wgpu::VertexStepMode = <Vertex as wrld::VertexLayout>::STEP_MODE;
+/// }
+///
+/// impl VertexBufferData {
+///     pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+///         <Self as wrld::VertexLayout>::LAYOUT
+///     }
+/// }
+///
+/// impl From<Vertex> for VertexBufferData {
+///     fn from(other_data_from_ident_to_into: Vertex) -> Self {
+///         Self {
+///             position: other_data_from_ident_to_into.position,
+///             scale: other_data_from_ident_to_into.scale
+///         }
+///     }
+/// }
+///
+/// impl<'a> From<&'a Vertex> for VertexBufferData {
+///     fn from(other_data_from_ident_to_into: &'a Vertex) -> Self {
+///         Self {
+///            position: other_data_from_ident_to_into.position,
+///            scale: other_data_from_ident_to_into.scale
+///         }
+///     }
+/// }
+///
+/// impl PartialEq<Vertex> for VertexBufferData {
+///     fn eq(&self, other_ident_data_boolean_condition: &Vertex) -> bool {
+///         position == other_ident_data_boolean_condition.position && scale: other_ident_data_boolean_condition.scale
+///     }
+/// }
+///
+/// impl FromIterator<Vertex> for Vec<VertexBufferData> {
+///     fn from_iter<T: IntoIterator<Item = Vertex>>(iter: T) -> Self {
+///         let mut vec_data_from_ident_from_iterator = Vec::new();
+///
+///         for c in iter {
+///             vec_data_from_ident_from_iterator.push(c.into());
+///         }
+///
+///         vec_data_from_ident_from_iterator
+///     }
+/// }
+///
+/// impl<'a> FromIterator<&'a Vertex> for Vec<VertexBufferData> {
+///     fn from_iter<T: IntoIterator<Item = &'a Vertex>>(iter: T) -> Self {
+///         let mut vec_data_from_ident_single_from_iterator : Vec<VertexBufferData> = Vec::new();
+///
+///         for c in iter {
+///             vec_data_from_ident_single_from_iterator.push(c.into());
+///         }
+///
+///         vec_data_from_ident_single_from_iterator
+///     }
+/// }
+///
+/// impl VertexBufferData {
+///     pub const fn const_into(other_ident_data_to_into_const: &Vertex) -> Self {
+///         Self {
+///             position: other_ident_data_to_into_const.position,
+///             scale: other_ident_data_to_into_const.scale
+///         }
+///     }
+/// }
+/// 
+/// impl Vertex {
+///     pub fn mutate<'a>(other_data_from_ident_to_mutate: &'a Vec<VertexBufferData>) -> &'a [u8] {
+///         bytemuck::cast_slice(other_data_from_ident_to_mutate.as_slice())
+///     }
+///
+///     pub fn transmute(other_data_from_ident_to_transmute: &[Self]) -> Vec<VertexBufferData> {
+///         other_data_from_ident_to_transmute.iter().collect::<Vec<VertexBufferData>>()
+///     }
+///
+///     pub fn pack<'a>(other_data_from_ident_to_pack: impl IntoIterator<Item = &'a Self>) -> Vec<u8> {
+///         let mut packed_bytes_from_ident = Vec::new();
+///
+///         for c in other_data_from_ident_to_pack {
+///             packed_bytes_from_ident.extend_from_slice(bytemuck::bytes_of(&VertexBufferData::from(c)));
+///         }
+///
+///         packed_bytes_from_ident
+///     }
+///
+///     pub const fn packed_len(count: usize) -> usize {
+///         count * ::core::mem::size_of::<VertexBufferData>()
+///     }
+///
+///     pub fn write_packed(src: &[Self], dst: &mut [u8]) -> Result<usize, wrld::BufferTooSmall> {
+///         let required = Self::packed_len(src.len());
+///
+///         if dst.len() < required {
+///             return Err(wrld::BufferTooSmall { required, len: dst.len() });
+///         }
+///
+///         let chunks = dst.chunks_exact_mut(::core::mem::size_of::<VertexBufferData>());
+///
+///         for (c, chunk) in src.iter().zip(chunks) {
+///             chunk.copy_from_slice(bytemuck::bytes_of(&VertexBufferData::from(c)));
+///         }
+///
+///         Ok(required)
+///     }
+///
+///     pub fn unpack_into(bytes: &[u8], dst: &mut [Self]) -> Result<(), wrld::UnpackError> {
+///         let stride = ::core::mem::size_of::<VertexBufferData>();
+///
+///         if bytes.len() % stride != 0 {
+///             return Err(wrld::UnpackError::PartialRecord { len: bytes.len(), stride });
+///         }
+///
+///         if bytes.len() / stride != dst.len() {
+///             return Err(wrld::UnpackError::CountMismatch { records: bytes.len() / stride, len: dst.len() });
+///         }
+///
+///         for (dst_data_from_ident_to_unpack, chunk) in dst.iter_mut().zip(bytes.chunks_exact(stride)) {
+///             let packed_data_from_bytes_to_unpack: VertexBufferData = bytemuck::pod_read_unaligned(chunk);
+///
+///             dst_data_from_ident_to_unpack.position = packed_data_from_bytes_to_unpack.position;
+///             dst_data_from_ident_to_unpack.scale = packed_data_from_bytes_to_unpack.scale;
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// 
+/// macro_rules! vertex_const_into {
+///     ($data: expr) => {
+///         VertexBufferData::const_into(&$data)
+///     };
+/// }
+///
+/// macro_rules! mutate_vertex {
+///     ($data: expr) => {
+///         Vertex::mutate(&Vertex::transmute($data))
+///     };
+/// }
+/// ```
+/// Also bytemuck is used for converting structure data to wgpu
+/// 
+/// `Vertex::desc()` describe `Vertex` as it is in memory, use `VertexBufferData::desc()` for the buffer filled with `Vertex::mutate`.
+/// The step mode is taken from `Vertex`, so BufferData need to be used with Desc or DescInstance.
+/// At least one field need a vertex attribute, an empty packed structure is a compile error.
+/// 
+/// ## How to use it ?
+/// 
+/// When you create any chaotic structure for wrld. Just put wrld::BufferData derive macro at the top
+/// 
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc, wrld::BufferData)]
+/// struct Vertex {
+///     texture: SomeTextureType,
+///     #[f32x3(0)] position: [f32; 3],
+///     message: String,
+///     #[f32x3(1)] scale: [f32; 3]
+/// }
+/// ```
+/// 
+/// ### Single variable conversion.
+/// 
+/// If you only need to convert a single variable. You can do that.
+/// 
+/// ```ignore
+/// let data : VertexBufferData = Vertex { 
+///     texture: SomeTextureType::new(), 
+///     position: [0.0, 0.0, 0.0], 
+///     message: String::from("something"),
+///     scale: [1.0, 1.0, 1.0]
+/// }.into()
+/// ```
+/// 
+/// If you however want to convert a constant vertex variable.
+/// 
+/// ```ignore
+/// const data : Vertex = Vertex { 
+///     texture: SomeTextureType::new(), 
+///     position: [0.0, 0.0, 0.0], 
+///     message: String::from("something"),
+///     scale: [1.0, 1.0, 1.0]
+/// }
+/// const vertex_buffer_data = VertexBufferData::const_into(&data);
+/// // or
+/// const vertex_buffer_data_new = vertex_const_into!(data);
+/// ```
+/// 
+/// ### Array conversion
+/// 
+/// Array conversion is a little bit more complex. We can't use the .into() because rust will not allow that.
+/// This is why you will need to transmute the const array first and then mutate it.
+/// 
+/// ```ignore
+/// const data : [Vertex] = [Vertex { 
+///     texture: SomeTextureType::new(), 
+///     position: [0.0, 0.0, 0.0], 
+///     message: String::from("something"),
+///     scale: [1.0, 1.0, 1.0]
+/// }, Vertex { 
+///     texture: SomeTextureType::new(), 
+///     position: [0.0, 1.0, 0.0], 
+///     message: String::from("something 2"),
+///     scale: [1.0, 1.0, 1.0]
+/// }]
+/// 
+/// fn main() {
+///     let arr : &[u8] = Vertex::mutate(&Vertex::transmute(data));
+///     // or
+///     let arr_new : &[u8] = mutate_vertex!(data);
+/// 
+///     // With wgpu create_buffer_init
+///     let device = wgpu::Device::new()
+///     
+///     let vertex_buffer = device.create_buffer_init(
+///         &wgpu::utils::BufferInitDescriptor {
+///             label: Some("Buffer init"),
+///             contents: Vertex::mutate(&Vertex::transmute(data)),
+///             usage: wgpu::BufferUsages::VERTEX
+///     })
+/// 
+///     // or
+/// 
+///     let vertex_buffer_new = device.create_buffer_init(
+///         &wgpu::utils::BufferInitDescriptor {
+///             label: Some("Buffer init"),
+///             contents: mutate_vertex!(data),
+///             usage: wgpu::BufferUsages::VERTEX
+///     })
+/// }
+/// ```
+/// 
+/// ### Runtime data
+/// 
+/// The conversions are not tied to `'static` data, `transmute` take any slice and `Vertex::pack` take any slice or iterator
+/// of references and return the packed bytes, so meshes built at runtime in a `Vec` can be uploaded directly.
+/// `mutate_vertex!` also work with a reference to a `Vec`.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::Desc, wrld::BufferData)]
+/// struct Vertex {
+///     #[f32x2(0)] position: [f32; 2],
+///     name: String,
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// 
+/// let vertices: Vec<Vertex> = (0..3).map(|i| Vertex {
+///     position: [i as f32, 0.0],
+///     name: format!("vertex {}", i),
+///     color: [1.0; 4]
+/// }).collect();
+/// 
+/// let bytes: Vec<u8> = Vertex::pack(&vertices);
+/// 
+/// assert_eq!(bytes.len(), 3 * std::mem::size_of::<VertexBufferData>());
+/// assert_eq!(bytes, mutate_vertex!(&vertices));
+/// assert_eq!(Vertex::pack(vertices.iter().skip(1)).len(), 2 * 24);
+/// ```
+/// 
+/// ### Packing without allocation
+/// 
+/// `Vertex::write_packed(src, dst)` write the packed structures directly into a byte slice, like a mapped staging buffer
+/// or a scratch buffer reused every frame, and return the number of bytes written. `Vertex::packed_len(n)` give the size needed
+/// for `n` structures. When `dst` is too small nothing is written and a `wrld::BufferTooSmall` error is returned.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::DescInstance, wrld::BufferData)]
+/// struct Instance {
+///     #[f32x3(0)] position: [f32; 3],
+///     name: &'static str,
+///     #[f32(1)] scale: f32
+/// }
+/// 
+/// let instances = [
+///     Instance { position: [0.0; 3], name: "first", scale: 1.0 },
+///     Instance { position: [1.0; 3], name: "second", scale: 2.0 }
+/// ];
+/// 
+/// let mut scratch = vec![0u8; Instance::packed_len(16)];
+/// 
+/// assert_eq!(Instance::write_packed(&instances, &mut scratch), Ok(32));
+/// assert_eq!(&scratch[..32], Instance::pack(&instances).as_slice());
+/// assert_eq!(
+///     Instance::write_packed(&instances, &mut scratch[..20]),
+///     Err(wrld::BufferTooSmall { required: 32, len: 20 })
+/// );
+/// ```
+/// 
+/// ### Reading back
+/// 
+/// `Vertex::unpack_into(bytes, dst)` decode packed structures, like an instance buffer updated by a compute shader and read back,
+/// and write the annotated fields into `dst`. The other fields are left untouched. The bytes need to hold exactly one packed
+/// structure per element of `dst`, otherwise a `wrld::UnpackError` is returned and `dst` is not modified.
+/// ```
+/// #[repr(C)]
+/// #[derive(wrld::DescInstance, wrld::BufferData)]
+/// struct Particle {
+///     #[f32x3(0)] position: [f32; 3],
+///     name: String,
+///     #[f32(1)] life: f32
+/// }
+/// 
+/// let mut particles = vec![
+///     Particle { position: [0.0; 3], name: String::from("spark"), life: 1.0 }
+/// ];
+/// 
+/// let bytes = Particle::pack(&[Particle { position: [1.0, 2.0, 3.0], name: String::new(), life: 0.5 }]);
+/// 
+/// Particle::unpack_into(&bytes, &mut particles).unwrap();
+/// 
+/// assert_eq!((particles[0].position, particles[0].life), ([1.0, 2.0, 3.0], 0.5));
+/// assert_eq!(particles[0].name, "spark");
+/// assert_eq!(
+///     Particle::unpack_into(&bytes[..10], &mut particles),
+///     Err(wrld::UnpackError::PartialRecord { len: 10, stride: 16 })
+/// );
+/// ```
+/// 
+/// macro name are formated like this.
+/// - struct name will be all lowercase
+/// - struct that have uppercase letter in his name are prefix with _ and the letter in question except for the starting letter.
+/// 
+/// ### Example
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(wrld::Desc, wrld::BufferData)]
+/// struct VertexData {
+///     #[f32x2(0)] position: [f32; 2]
+///     #[f32x4(1)] color: [f32; 4]
+/// }
+/// 
+/// // is equal to
+/// 
+/// macro_rules! vertex_data_const_into {
+///     ($data: expr) => {
+///         VertexDataBufferData::const_into(&$data)
+///     };
+/// }
+/// macro_rules! mutate_vertex_data { 
+///     ($data: expr) => {
+///         VertexData::mutate(&VertexData::transmute($data))
+///     }; 
+/// }
+/// ```
+/// 
+/// ## Crate path
+/// 
+/// BufferData use the `#[wrld(wgpu = "...", bytemuck = "...")]` paths of the structure for the generated structure, its bytemuck derives and its desc.
+/// 
+/// ## Why you have created a another macro instead of putting it in wrld::Desc ?
+/// 
+/// 1. Prevent wrld to be too much invasive.
+/// 2. BufferData is not always needed.
+/// 3. BufferData is made to handle chaotic structure and not ordered one. (related to 2.)
+/// 
+/// There is also know problem about naming const variable the same as the quote generated code variable.
+/// There is a simple workaround that is to name const variable all uppercase or just change name of the const variable.
+/// However this problem only occurs on const variable
+#[proc_macro_derive(BufferData, attributes(wrld))]
+pub fn derive_wrld_buffer_data(item: TokenStream) -> TokenStream {
+    macros::derive_wrld_buffer_data(item)
+}
+
+/// Uniform derive macro generate a `#[repr(C)]` Pod structure `<Name>Uniform` following the std140 layout of WGSL uniform buffers,
+/// with explicit padding fields, and the conversion from the structure.
+/// 
+/// Supported field types are `f32`, `u32`, `i32`, vectors (`[T; 2..=4]`), matrices (`[[f32; R]; C]`, column major, 2 to 4 columns and rows)
+/// and arrays of them. Uniform arrays need an element stride multiple of 16 (`[[f32; 4]; N]` and not `[f32; N]`).
+/// 
+/// The structure also get :
+/// - `WGSL` const holding the matching WGSL structure
+/// - `MIN_BINDING_SIZE` const, the `min_binding_size` of the bind group layout entry
+/// 
+/// ## Example
+/// ```
+/// #[derive(wrld::Uniform)]
+/// struct Camera {
+///     view_proj: [[f32; 4]; 4],
+///     position: [f32; 3],
+///     time: f32,
+///     normal: [[f32; 3]; 3]
+/// }
+/// 
+/// assert_eq!(Camera::WGSL, "struct Camera {
+///     view_proj: mat4x4<f32>,
+///     position: vec3<f32>,
+///     time: f32,
+///     normal: mat3x3<f32>,
+/// }
+/// ");
+/// assert_eq!(Camera::MIN_BINDING_SIZE.unwrap().get(), 128);
+/// 
+/// let camera = Camera { view_proj: [[0.0; 4]; 4], position: [1.0, 2.0, 3.0], time: 0.5, normal: [[1.0; 3]; 3] };
+/// let uniform = CameraUniform::from(&camera);
+/// 
+/// assert_eq!(uniform.normal[0], [1.0, 1.0, 1.0, 0.0]);
+/// assert_eq!(wrld::bytemuck::bytes_of(&uniform).len(), 128);
+/// ```
+/// 
+/// The wgpu and bytemuck paths can be changed with `#[wrld(wgpu = "...", bytemuck = "...")]` like Desc.
+#[proc_macro_derive(Uniform, attributes(wrld))]
+pub fn derive_wrld_uniform(item: TokenStream) -> TokenStream {
+    uniform::derive_wrld_uniform(item)
+}
+
+/// Storage derive macro write a structure following the std430 layout of WGSL storage buffers.
+/// 
+/// Supported field types are the ones of Uniform, structures deriving Storage and arrays of them.
+/// The last field can be a runtime-sized array, `Vec<T>` or `&[T]`, written as `array<T>` in WGSL.
+/// 
+/// The structure get :
+/// - `WGSL` const holding the matching WGSL structure, nested structures need to be declared too (with their own `WGSL`)
+/// - `MIN_BINDING_SIZE` const, the `min_binding_size` of the bind group layout entry (with one element in the runtime-sized array)
+/// - `to_storage_bytes()` writing the structure and the elements of the runtime-sized array
+/// - `storage_size(len)` giving the size of the buffer for `len` elements, when there is a runtime-sized array
+/// - the `wrld::Storage` trait implementation, when there is no runtime-sized array
+/// 
+/// The offset of every field and the size of the structure are checked at compile time against the std430 layout.
+/// 
+/// ## Example
+/// ```
+/// #[derive(wrld::Storage)]
+/// struct Particle {
+///     position: [f32; 3],
+///     life: f32,
+///     velocity: [f32; 3]
+/// }
+/// 
+/// #[derive(wrld::Storage)]
+/// struct Particles {
+///     count: u32,
+///     particles: Vec<Particle>
+/// }
+/// 
+/// assert_eq!(Particles::WGSL, "struct Particles {
+///     count: u32,
+///     particles: array<Particle>,
+/// }
+/// ");
+/// assert_eq!(Particles::storage_size(2), 16 + 2 * 32);
+/// 
+/// let particles = Particles {
+///     count: 1,
+///     particles: vec![Particle { position: [1.0, 2.0, 3.0], life: 1.0, velocity: [0.0; 3] }]
+/// };
+/// 
+/// assert_eq!(particles.to_storage_bytes().len(), 48);
+/// ```
+/// 
+/// The wgpu and bytemuck paths can be changed with `#[wrld(wgpu = "...", bytemuck = "...")]` like Desc.
+#[proc_macro_derive(Storage, attributes(wrld))]
+pub fn derive_wrld_storage(item: TokenStream) -> TokenStream {
+    storage::derive_wrld_storage(item)
+}
+
+/// BindGroup derive macro describe a bind group from a structure holding its resources.
+/// 
+/// Each field holding a resource take a binding attribute, the binding index followed by options :
+/// - `#[uniform(0)]` : uniform buffer, options `dynamic` and `min_binding_size = <Uniform type>`
+/// - `#[storage(0)]` : storage buffer, options `read_only`, `dynamic` and `min_binding_size = <Storage type>`
+/// - `#[texture(0)]` : sampled texture, options `sample_type = "float" | "unfilterable_float" | "depth" | "sint" | "uint"` (float by default),
+///   `dimension = "1d" | "2d" | "2d_array" | "cube" | "cube_array" | "3d"` (2d by default) and `multisampled`
+/// - `#[sampler(0)]` : sampler, options `filtering` (default), `non_filtering` and `comparison`
+/// 
+/// Every binding take a `visibility = "vertex | fragment | compute"` option. The default visibility is vertex and fragment,
+/// it can be changed for the whole structure with `#[wrld(visibility = "...")]`.
+/// 
+/// Fields can be buffers, buffer bindings, texture views, samplers or references to them (see `wrld::AsBindingResource`).
+/// 
+/// The structure get :
+/// - `LAYOUT_ENTRIES` const holding the bind group layout entries
+/// - `layout_descriptor(label)` returning the bind group layout descriptor
+/// - `entries(&self)` returning the bind group entries built from the fields
+/// 
+/// ## Example
+/// ```
+/// use wrld::wgpu;
+/// 
+/// #[derive(wrld::Uniform)]
+/// struct MaterialParams {
+///     color: [f32; 4]
+/// }
+/// 
+/// #[derive(wrld::BindGroup)]
+/// #[wrld(visibility = "fragment")]
+/// struct MaterialBindings<'a> {
+///     #[uniform(0, min_binding_size = MaterialParams, visibility = "vertex | fragment")] params: &'a wgpu::Buffer,
+///     #[texture(1)] albedo: &'a wgpu::TextureView,
+///     #[sampler(2)] sampler: &'a wgpu::Sampler
+/// }
+/// 
+/// assert_eq!(MaterialBindings::LAYOUT_ENTRIES[1].visibility, wgpu::ShaderStages::FRAGMENT);
+/// assert_eq!(MaterialBindings::LAYOUT_ENTRIES[2].ty, wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering));
+/// ```
+/// Then with a device :
+/// ```ignore
+/// let layout = device.create_bind_group_layout(&MaterialBindings::layout_descriptor(Some("material")));
+/// let bindings = MaterialBindings { params: &params, albedo: &albedo, sampler: &sampler };
+/// 
+/// let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+///     label: Some("material"),
+///     layout: &layout,
+///     entries: &bindings.entries()
+/// });
+/// ```
+/// 
+/// The wgpu path can be changed with `#[wrld(wgpu = "...")]` like Desc.
+#[proc_macro_derive(BindGroup, attributes(wrld, uniform, storage, texture, sampler))]
+pub fn derive_wrld_bind_group(item: TokenStream) -> TokenStream {
+    bind_group::derive_wrld_bind_group(item)
+}
+
+/// PushConstants derive macro describe a push constant structure, following the WGSL layout.
+/// 
+/// The generated code depend on the selected wgpu version, push constants are named immediates in wgpu 30.
+/// 
+/// The structure get :
+/// - `SIZE` and `ALIGN` consts, `SIZE` is the `immediate_size` of the pipeline layout with wgpu 30
+/// - `PUSH_CONSTANT_RANGE` const with wgpu 0.13 and 0.19, `STAGES` const with wgpu 30
+/// - `WGSL` const holding the WGSL structure and its `var<push_constant>` (`var<immediate>` with wgpu 30) declaration, named in snake case
+/// - `bytes()` returning the bytes of the structure
+/// 
+/// The shader stages are set with `#[wrld(visibility = "vertex | fragment")]` (vertex and fragment by default).
+/// The size is checked at compile time against 128 bytes, the size guaranteed by the backends,
+/// the limit can be changed with `#[wrld(max_push_constant_size = 256)]`.
+/// 
+/// ## Example
+/// ```
+/// #[derive(wrld::PushConstants)]
+/// #[wrld(visibility = "vertex")]
+/// struct DrawData {
+///     transform: [[f32; 4]; 4],
+///     color: [f32; 3],
+///     id: u32
+/// }
+/// 
+/// assert_eq!(DrawData::SIZE, 80);
+/// assert_eq!(DrawData::bytes(&DrawData { transform: [[0.0; 4]; 4], color: [1.0; 3], id: 2 })[76..], 2u32.to_ne_bytes());
+/// ```
+/// 
+/// The wgpu and bytemuck paths can be changed with `#[wrld(wgpu = "...", bytemuck = "...")]` like Desc.
+#[proc_macro_derive(PushConstants, attributes(wrld))]
+pub fn derive_wrld_push_constants(item: TokenStream) -> TokenStream {
+    push_constant::derive_wrld_push_constants(item)
+}
+
+/// Varyings derive macro describe the interface between a vertex shader and a fragment shader.
+/// 
+/// Fields take a shader location in field order, starting from 0 or from `#[wrld(auto_location = <base>)]`.
+/// `#[location(N)]` set the location of a field, the following fields continue from it.
+/// 
+/// Field attributes :
+/// - `#[flat]`, `#[flat(first | either)]` : flat interpolation, required on integer types
+/// - `#[perspective]`, `#[linear]` with an optional sampling `center`, `centroid` or `sample`, like `#[perspective(centroid)]`
+/// - `#[builtin(position)]` : clip position (`[f32; 4]`), without location
+/// 
+/// Fields are f32, u32, i32 or vectors of them (`[T; 2..=4]`).
+/// 
+/// The structure get :
+/// - `WGSL` const holding the WGSL structure, used as the vertex output and the fragment input
+/// - `<FIELD>_LOCATION` consts holding the shader location of every field
+/// 
+/// ## Example
+/// ```
+/// #[derive(wrld::Varyings)]
+/// struct VertexOutput {
+///     #[builtin(position)] clip_position: [f32; 4],
+///     uv: [f32; 2],
+///     #[perspective(centroid)] normal: [f32; 3],
+///     #[flat] material: u32
+/// }
+/// 
+/// assert_eq!(VertexOutput::WGSL, "struct VertexOutput {
+///     @builtin(position) clip_position: vec4<f32>,
+///     @location(0) uv: vec2<f32>,
+///     @location(1) @interpolate(perspective, centroid) normal: vec3<f32>,
+///     @location(2) @interpolate(flat) material: u32,
+/// }
+/// ");
+/// assert_eq!(VertexOutput::MATERIAL_LOCATION, 2);
+/// ```
+#[proc_macro_derive(Varyings, attributes(wrld, location, builtin, flat, perspective, linear))]
+pub fn derive_wrld_varyings(item: TokenStream) -> TokenStream {
+    varyings::derive_wrld_varyings(item)
+}
+
+/// FragmentOutput derive macro describe the color targets written by a fragment shader.
+/// 
+/// Every field take a `#[target(<index>, <format>)]` attribute, the location of the output and the `wgpu::TextureFormat`
+/// of the color target, followed by options :
+/// - `blend = replace | alpha | premultiplied_alpha` : blend state of the target, no blending by default
+/// - `write_mask = "red | green | blue | alpha"` : written channels, all by default
+/// 
+/// Fields are f32, u32, i32 or vectors of them (`[T; 2..=4]`), matching the texture format : integer formats are written as
+/// u32 (`Uint`) or i32 (`Sint`) and cannot be blended, the other formats are written as f32.
+/// 
+/// The structure get :
+/// - `TARGETS` const holding the color targets of the fragment state, indexed by location (`None` for unused locations)
+/// - `WGSL` const holding the WGSL structure returned by the fragment shader
+/// 
+/// ## Example
+/// ```
+/// use wrld::wgpu;
+/// 
+/// #[derive(wrld::FragmentOutput)]
+/// struct GBuffer {
+///     #[target(0, Rgba16Float)] normal: [f32; 4],
+///     #[target(1, Rgba8UnormSrgb, blend = alpha)] albedo: [f32; 4],
+///     #[target(2, R32Uint)] material: u32
+/// }
+/// 
+/// assert_eq!(GBuffer::WGSL, "struct GBuffer {
+///     @location(0) normal: vec4<f32>,
+///     @location(1) albedo: vec4<f32>,
+///     @location(2) material: u32,
+/// }
+/// ");
+/// assert_eq!(GBuffer::TARGETS[1].as_ref().map(|target| target.blend), Some(Some(wgpu::BlendState::ALPHA_BLENDING)));
+/// ```
+/// The targets are given to the fragment state with `targets: &GBuffer::TARGETS`.
+/// 
+/// The wgpu path can be changed with `#[wrld(wgpu = "...")]` like Desc.
+#[proc_macro_derive(FragmentOutput, attributes(wrld, target))]
+pub fn derive_wrld_fragment_output(item: TokenStream) -> TokenStream {
+    fragment_output::derive_wrld_fragment_output(item)
+}
+
+/// PipelineConstants derive macro describe the WGSL `override` declarations specializing a shader.
+/// 
+/// Fields are bool, f32, i32 or u32, the scalar types of WGSL overrides, and can take a `#[constant(...)]` attribute :
+/// - `id = <N>` : numeric id of the override (`@id(N)`), used as the key of the pipeline constant instead of the name
+/// - `default = <literal>` : default value of the override in the shader
+/// 
+/// The structure get :
+/// - `WGSL` const holding the `override name: type = default;` declarations
+/// - `constants(&self)` returning the pipeline constants of the values as a `HashMap<String, f64>`
+/// - `constant_entries(&self)` returning the pipeline constants as `(&str, f64)` pairs
+/// 
+/// ## Example
+/// ```
+/// #[derive(wrld::PipelineConstants)]
+/// struct Specialization {
+///     #[constant(default = 16)] sample_count: u32,
+///     #[constant(id = 0, default = 0.5)] exposure: f32,
+///     shadows: bool
+/// }
+/// 
+/// assert_eq!(Specialization::WGSL, "override sample_count: u32 = 16u;
+/// @id(0) override exposure: f32 = 0.5;
+/// override shadows: bool;
+/// ");
+/// 
+/// let specialization = Specialization { sample_count: 4, exposure: 1.5, shadows: true };
+/// 
+/// assert_eq!(specialization.constant_entries(), [("sample_count", 4.0), ("0", 1.5), ("shadows", 1.0)]);
+/// assert_eq!(specialization.constants()["0"], 1.5);
+/// ```
+/// With wgpu 30, the entries are given to the pipeline compilation options :
+/// ```ignore
+/// let constants = specialization.constant_entries();
+/// 
+/// let compilation_options = wgpu::PipelineCompilationOptions {
+///     constants: &constants,
+///     ..Default::default()
+/// };
+/// ```
+#[proc_macro_derive(PipelineConstants, attributes(constant))]
+pub fn derive_wrld_pipeline_constants(item: TokenStream) -> TokenStream {
+    pipeline_constants::derive_wrld_pipeline_constants(item)
+}
+
+/// Generate a vertex structure from the location inputs of a WGSL vertex entry point, require the `shader` feature of wrld.
+/// 
+/// The shader path is relative to the crate root. The structure is `#[repr(C)]` and derive `wrld::Desc`, each input
+/// get a field of the same name with its explicit format attribute (`vec3<f32>` is `#[f32x3(N)] [f32; 3]`, `vec2<u32>` is `#[u32x2(N)] [u32; 2]`, ect...).
+/// 
+/// Attributes and visibility can be given before the name, and the entry point argument to use as last parameter
+/// when the entry point read multiple buffers. The fields have the visibility of the structure, `#[wrld(...)]` attributes
+/// (like `#[wrld(wgpu = "...")]`) are options of the Desc derive.
+/// 
+/// ## Example
+/// ```ignore
+/// // @vertex fn vs_main(@location(0) position: vec3<f32>, @location(1) id: u32) -> ...
+/// wrld::vertex_from_wgsl!("shaders/mesh.wgsl", "vs_main", #[derive(Clone, Copy)] #[wrld(wgpu = "crate::gfx::wgpu")] pub Vertex);
+/// ```
+/// into
+/// ```ignore
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// #[derive(::wrld::Desc)]
+/// #[wrld(wgpu = "crate::gfx::wgpu")]
+/// pub struct Vertex {
+///     #[f32x3(0u32)] pub position: [f32; 3],
+///     #[u32(1u32)] pub id: u32
+/// }
+/// ```
+#[cfg(feature = "shader")]
+#[proc_macro]
+pub fn vertex_from_wgsl(item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as shader::VertexFromWgsl);
+
+    shader::expand_vertex_from_wgsl(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
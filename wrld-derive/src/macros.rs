@@ -0,0 +1,794 @@
+use crate::converter::{convert_type_to_wgpu, has_type, is_mat_type, infer_type_name, convert_mat_type_to_wgou, WGPUData};
+use crate::parser::TokenVertexFormat;
+use crate::format::{VertexFormat, WgpuVersion};
+use crate::memory::{AddressSpace, ShaderType};
+use crate::parser::{parse_attrs, is_bare_attr, type_dimensions, private_path, AttrWrld};
+use crate::shader::{check_shader, ShaderAttribute};
+
+#[derive(Debug)]
+pub struct Entity {
+    pub fields: Vec<EntityFields>
+}
+
+#[derive(Debug)]
+struct EntityFieldsAttrs {
+    name: String,
+    data: Option<u32>,
+    ty: Option<String>,
+    span: proc_macro2::Span
+}
+
+#[derive(Debug)]
+pub struct EntityFields {
+    attrs: Vec<EntityFieldsAttrs>,
+    pub name: proc_macro2::Ident,
+    pub ty: syn::Type,
+    pub vis: syn::Visibility
+}
+
+/// A field attribute converted to its vertex formats, one format per shader location.
+struct VertexAttr<'a> {
+    field: &'a EntityFields,
+    attr: &'a EntityFieldsAttrs,
+    formats: Vec<WGPUData>
+}
+
+/// Fold a list of errors into a single one so that every error is reported in one pass.
+pub fn combine_errors(errors: Vec<syn::Error>) -> syn::Result<()> {
+    let mut errors = errors.into_iter();
+
+    match errors.next() {
+        None => Ok(()),
+        Some(mut first) => {
+            errors.for_each(|e| first.combine(e));
+            Err(first)
+        }
+    }
+}
+
+/// `snake_case` name of a `CamelCase` identifier.
+pub fn snake_case(ident: &syn::Ident) -> String {
+    let ident_regex_lowercase = regex::Regex::new(r"(?P<M>[A-Z])").expect("valid regex");
+    let ident_string = ident.to_string();
+    let replace_all = ident_regex_lowercase.replace_all(ident_string.as_str(), "_$M");
+    let mut result = replace_all.to_ascii_lowercase();
+    
+    if result.starts_with('_') {
+        result.remove(0);
+    }
+
+    result
+}
+
+pub fn get_named_fields<'a>(
+    ident: &syn::Ident,
+    data: &'a syn::Data,
+    derive_name: &str
+) -> syn::Result<&'a syn::punctuated::Punctuated<syn::Field, syn::Token![,]>> {
+    if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(syn::FieldsNamed { ref named, ..}),
+        ..
+    }) = data 
+    {
+        Ok(named)
+    } else {
+        Err(syn::Error::new(ident.span(), format!("{} only support struct with named fields", derive_name)))
+    }
+}
+
+fn get_entity_field(field: &syn::Field, errors: &mut Vec<syn::Error>) -> Option<EntityFields> {
+    let mut attrs: Vec<EntityFieldsAttrs> = Vec::new();
+    let mut vertex_span: Option<proc_macro2::Span> = None;
+
+    parse_attrs(&field.attrs,Box::new(|attr| {
+        let name = attr.segment.ident.to_string();
+        let span = attr.segment.ident.span();
+
+        if name == "vertex" {
+            vertex_span = Some(span);
+
+            let vertex = if is_bare_attr(attr.attribute) {
+                Ok(crate::parser::AttrVertex { location: None })
+            } else {
+                attr.attribute.parse_args::<crate::parser::AttrVertex>()
+            };
+
+            let vertex = match vertex {
+                Ok(vertex) => vertex,
+                Err(e) => {
+                    errors.push(e);
+                    return
+                }
+            };
+
+            let inferred = type_dimensions(&field.ty)
+                .and_then(|(scalar, dims)| infer_type_name(&scalar, &dims));
+
+            match inferred {
+                Some((name, ty)) => attrs.push(EntityFieldsAttrs {
+                    name,
+                    data: vertex.location,
+                    ty,
+                    span
+                }),
+                None => errors.push(syn::Error::new(
+                    syn::spanned::Spanned::span(&field.ty),
+                    "Cannot infer the vertex format of this type, use an explicit format attribute like #[f32x4(0)]"
+                ))
+            }
+
+            return
+        }
+
+        if is_mat_type(&name) {
+            match attr.attribute.parse_args::<crate::parser::AttrMat>() {
+                Ok(mat) => attrs.push(EntityFieldsAttrs {
+                    name,
+                    data: mat.data,
+                    ty: Some(mat.ident.to_string()),
+                    span
+                }),
+                Err(e) => errors.push(e)
+            }
+
+            return
+        }
+
+        // Attributes that are not a vertex format (doc, serde, ...) are not for us.
+        if !has_type(&name) {
+            return
+        }
+
+        let data = if is_bare_attr(attr.attribute) {
+            Ok(None)
+        } else {
+            attr.attribute.parse_args::<syn::LitInt>()
+                .map_err(|e| syn::Error::new(e.span(), "Only integer is authorize for shader location data"))
+                .and_then(|lint| lint.base10_parse::<u32>())
+                .map(Some)
+        };
+
+        match data {
+            Ok(data) => attrs.push(EntityFieldsAttrs {
+                name,
+                data,
+                ty: None,
+                span
+            }),
+            Err(e) => errors.push(e)
+        }
+    }));
+
+    if let Some(span) = vertex_span {
+        if attrs.len() > 1 {
+            errors.push(syn::Error::new(
+                span,
+                "#[vertex] cannot be used with an explicit format attribute, remove one of them"
+            ));
+        }
+    }
+
+    let Some(name) = field.ident.clone() else {
+        errors.push(syn::Error::new_spanned(field, "Field need to be named"));
+        return None
+    };
+
+    Some(EntityFields {
+        attrs,
+        name,
+        ty: field.ty.clone(),
+        vis: field.vis.clone()
+    })
+}
+
+pub fn get_entity<'a>(fields: impl IntoIterator<Item = &'a syn::Field>, errors: &mut Vec<syn::Error>) -> Entity {
+    Entity {
+        fields: fields.into_iter().filter_map(|field| get_entity_field(field, errors)).collect()
+    }
+}
+
+/// Shader types of entity fields placed in a buffer of the address space, for Uniform, Storage and PushConstants.
+/// The fields that are not host-shareable are reported in `errors`.
+pub fn get_shader_fields<'a>(
+    fields: impl IntoIterator<Item = &'a EntityFields>,
+    space: AddressSpace,
+    errors: &mut Vec<syn::Error>
+) -> Vec<(proc_macro2::Ident, ShaderType)> {
+    fields.into_iter().filter_map(|field| match ShaderType::from_type(&field.ty, space) {
+        Ok(shader_type) => Some((field.name.clone(), shader_type)),
+        Err(e) => {
+            errors.push(e);
+            None
+        }
+    }).collect()
+}
+
+/// Options of the `#[wrld(...)]` attribute used by Desc, DescInstance and BufferData, which share the attribute.
+const DESC_WRLD_OPTIONS: [&str; 6] = ["auto_location", "wgpu", "bytemuck", "shader", "entry", "input"];
+
+/// Parse the `#[wrld(...)]` struct attributes of `#[derive(<derive>)]`, accepting the `supported` options.
+pub fn get_wrld_attrs(attrs: &[syn::Attribute], derive: &str, supported: &[&str], errors: &mut Vec<syn::Error>) -> AttrWrld {
+    let mut wrld = AttrWrld::default();
+
+    parse_attrs(attrs, Box::new(|attr| {
+        if attr.segment.ident == "wrld" {
+            if let Err(e) = wrld.parse_into(attr.attribute, derive, supported) {
+                errors.push(e);
+            }
+        }
+    }));
+
+    wrld
+}
+
+/// Convert every field attribute to its vertex formats and check that no shader location is used twice.
+///
+/// With `auto_location`, attributes without location take the location following the previous attribute,
+/// matrices taking one location per column.
+fn resolve_attributes<'a>(
+    fields: impl IntoIterator<Item = &'a EntityFields>,
+    auto_location: Option<u32>,
+    errors: &mut Vec<syn::Error>
+) -> Vec<VertexAttr<'a>> {
+    let mut resolved: Vec<VertexAttr> = Vec::new();
+    let mut shader_locations: Vec<(u32, &EntityFields)> = Vec::new();
+    let mut next_location = auto_location;
+
+    for field in fields {
+        for attr in &field.attrs {
+            let Some(location) = attr.data.or(next_location) else {
+                errors.push(syn::Error::new(
+                    attr.span,
+                    "Missing shader location, add it to the attribute or use #[wrld(auto_location)] on the struct"
+                ));
+                continue
+            };
+
+            let formats = match &attr.ty {
+                None => convert_type_to_wgpu(&attr.name, location).map(|format| vec![format]),
+                Some(ty) => convert_mat_type_to_wgou(&attr.name, location, ty)
+            };
+
+            let formats = match formats {
+                Ok(formats) => formats,
+                Err(e) => {
+                    errors.push(syn::Error::new(attr.span, e));
+                    continue
+                }
+            };
+
+            if auto_location.is_some() {
+                next_location = Some(location + formats.len() as u32);
+            }
+
+            for format in &formats {
+                let location = format.shader_location;
+
+                if let Some((_, other)) = shader_locations.iter().find(|(l, _)| *l == location) {
+                    errors.push(syn::Error::new(
+                        other.name.span(),
+                        format!("Shader location {} is used by `{}` and `{}`", location, other.name, field.name)
+                    ));
+                    errors.push(syn::Error::new(
+                        field.name.span(),
+                        format!("Shader location {} is already used by `{}`", location, other.name)
+                    ));
+                } else {
+                    shader_locations.push((location, field));
+                }
+            }
+
+            resolved.push(VertexAttr { field, attr, formats });
+        }
+    }
+
+    resolved
+}
+
+/// Build a `<FIELD>_LOCATION` associated const holding the first shader location of every annotated field.
+fn build_location_consts(resolved: &[VertexAttr]) -> Vec<proc_macro2::TokenStream> {
+    let mut names: Vec<&proc_macro2::Ident> = Vec::new();
+
+    resolved.iter().filter_map(|i| {
+        if names.contains(&&i.field.name) {
+            return None
+        }
+
+        names.push(&i.field.name);
+
+        let const_name = quote::format_ident!(
+            "{}_LOCATION",
+            i.field.name.to_string().trim_start_matches("r#").to_ascii_uppercase()
+        );
+        let location = i.formats[0].shader_location;
+
+        Some(quote::quote! {
+            pub const #const_name: u32 = #location;
+        })
+    }).collect()
+}
+
+/// Shader inputs of the attributes as `(location, name, format)`.
+///
+/// Matrices are written one column per location, `<field>_<column>`.
+fn shader_inputs(resolved: &[VertexAttr]) -> Vec<(u32, String, VertexFormat)> {
+    let mut inputs = Vec::new();
+
+    for i in resolved {
+        let name = i.field.name.to_string();
+        let name = name.trim_start_matches("r#");
+
+        for (column, format) in i.formats.iter().enumerate() {
+            let name = if i.attr.ty.is_some() {
+                format!("{}_{}", name, column)
+            } else {
+                name.to_string()
+            };
+
+            inputs.push((format.shader_location, name, format.wgpu_type.ty));
+        }
+    }
+
+    inputs
+}
+
+/// Write the WGSL vertex input structure matching the attributes, named as `target`.
+fn build_wgsl_struct(target: &syn::Ident, resolved: &[VertexAttr]) -> String {
+    let mut wgsl = format!("struct {} {{\n", target);
+
+    for (location, name, format) in shader_inputs(resolved) {
+        wgsl.push_str(&format!("    @location({}) {}: {},\n", location, name, format.wgsl_type()));
+    }
+
+    wgsl.push_str("}\n");
+    wgsl
+}
+
+/// Write the GLSL `layout(location = N) in` declarations matching the attributes.
+fn build_glsl_inputs(resolved: &[VertexAttr]) -> String {
+    shader_inputs(resolved).into_iter()
+        .map(|(location, name, format)| format!("layout(location = {}) in {} {};\n", location, format.glsl_type(), name))
+        .collect()
+}
+
+/// Write the HLSL vertex input structure matching the attributes, named as `target`, with a `TEXCOORD<location>` semantic per input.
+fn build_hlsl_struct(target: &syn::Ident, resolved: &[VertexAttr]) -> String {
+    let mut hlsl = format!("struct {} {{\n", target);
+
+    for (location, name, format) in shader_inputs(resolved) {
+        hlsl.push_str(&format!("    {} {} : TEXCOORD{};\n", format.hlsl_type(), name, location));
+    }
+
+    hlsl.push_str("};\n");
+    hlsl
+}
+
+/// Check the attributes against `#[wrld(shader = "...", entry = "...")]` when present.
+fn build_shader_check(wrld: &AttrWrld, resolved: &[VertexAttr], errors: &mut Vec<syn::Error>) -> proc_macro2::TokenStream {
+    let Some(shader) = &wrld.shader else {
+        for option in [&wrld.entry, &wrld.input].into_iter().flatten() {
+            errors.push(syn::Error::new(option.span(), "this option require a `shader` option"));
+        }
+
+        return proc_macro2::TokenStream::new()
+    };
+
+    let attributes: Vec<ShaderAttribute> = resolved.iter().flat_map(|i| {
+        i.formats.iter().map(|format| ShaderAttribute {
+            location: format.shader_location,
+            format: format.wgpu_type.ty,
+            field: i.field.name.clone()
+        })
+    }).collect();
+
+    check_shader(shader, wrld.entry.as_ref(), wrld.input.as_ref(), &attributes, errors)
+}
+
+fn process_wgpu_type(
+    format: &WGPUData, 
+    attrs: &mut Vec<proc_macro2::TokenStream>,
+    offset: proc_macro2::TokenStream,
+    wgpu: &proc_macro2::TokenStream
+) {
+    let tty = TokenVertexFormat { attribute: format.wgpu_type.ty, wgpu: wgpu.clone() };
+    let shader_location = format.shader_location;
+
+    attrs.push(quote::quote! {
+        #wgpu::VertexAttribute {
+            offset: #offset,
+            format: #tty,
+            shader_location: #shader_location
+        }
+    });
+}
+
+/// Build the vertex attributes of `target` from the annotated fields.
+///
+/// Offsets are taken from the real field position in `target` (`offset_of!`) so that
+/// padding and non attribute fields are accounted for.
+fn build_vertex_attributes(
+    target: &syn::Ident,
+    resolved: &[VertexAttr],
+    wgpu: &proc_macro2::TokenStream
+) -> Vec<proc_macro2::TokenStream> {
+    let mut attrs : Vec<proc_macro2::TokenStream> = Vec::new();
+
+    for i in resolved {
+        let name = &i.field.name;
+
+        // Matrix columns are laid out one after the other inside the field.
+        let mut column_offset: u64 = 0;
+        for format in &i.formats {
+            let offset = quote::quote! {
+                (::core::mem::offset_of!(#target, #name) as #wgpu::BufferAddress + #column_offset)
+            };
+            process_wgpu_type(format, &mut attrs, offset, wgpu);
+            column_offset += format.wgpu_type.offset;
+        }
+    }
+
+    attrs
+}
+
+/// Build a const assertion for every annotated field checking that the size of the field type
+/// is the byte size of its vertex format. The assertion is spanned on the field type.
+fn build_size_checks(resolved: &[VertexAttr]) -> Vec<proc_macro2::TokenStream> {
+    resolved.iter().map(|i| {
+        let ty = &i.field.ty;
+        let size = i.formats.iter().map(|format| format.wgpu_type.offset).sum::<u64>() as usize;
+        let message = format!(
+            "wrld: the type of field `{}` does not have the size of #[{}] ({} bytes)",
+            i.field.name, i.attr.name, size
+        );
+
+        quote::quote_spanned! {syn::spanned::Spanned::span(ty)=>
+            const _: () = ::core::assert!(::core::mem::size_of::<#ty>() == #size, #message);
+        }
+    }).collect()
+}
+
+/// Generate the `wrld::VertexLayout` implementation of `target` and its `desc` function,
+/// `ARRAY_STRIDE` being the real size of `target`.
+fn desc_tokens(
+    target: &syn::Ident,
+    attrs: &[proc_macro2::TokenStream],
+    step_mode: proc_macro2::TokenStream,
+    wgpu: &proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let private = private_path();
+
+    // `VertexLayout::LAYOUT` need the wgpu crate, wrld only has the wgpu 0.13 types.
+    let layout = match WgpuVersion::selected() {
+        WgpuVersion::V0_13 => quote::quote! {
+            #wgpu::VertexBufferLayout {
+                array_stride: <Self as #private::VertexLayout>::ARRAY_STRIDE,
+                step_mode: <Self as #private::VertexLayout>::STEP_MODE,
+                attributes: <Self as #private::VertexLayout>::ATTRIBUTES
+            }
+        },
+        _ => quote::quote! { <Self as #private::VertexLayout>::LAYOUT }
+    };
+
+    quote::quote! {
+        impl #private::VertexLayout for #target {
+            const ATTRIBUTES: &'static [#wgpu::VertexAttribute] = &[#(#attrs),*];
+            const ARRAY_STRIDE: #wgpu::BufferAddress = ::core::mem::size_of::<#target>() as #wgpu::BufferAddress;
+            const STEP_MODE: #wgpu::VertexStepMode = #step_mode;
+        }
+
+        impl #target {
+            pub fn desc<'a>() -> #wgpu::VertexBufferLayout<'a> {
+                #layout
+            }
+        }
+    }
+}
+
+fn require_repr_c(ident: &syn::Ident, attrs : &[syn::Attribute]) -> syn::Result<()> {
+    let mut valid = false;
+    let mut errors: Vec<syn::Error> = Vec::new();
+
+    parse_attrs(attrs, Box::new(|attr| {
+        if attr.segment.ident != "repr" {
+            return
+        }
+
+        let reprs = attr.attribute.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated
+        );
+
+        match reprs {
+            Ok(reprs) => if reprs.iter().any(|repr| repr.path().is_ident("C") || repr.path().is_ident("transparent")) {
+                valid = true;
+            },
+            Err(e) => errors.push(e)
+        }
+    }));
+
+    combine_errors(errors)?;
+
+    if !valid {
+        return Err(syn::Error::new(
+            ident.span(),
+            "wrld::Desc derive macro require #[repr(C)] or #[repr(transparent)] attribute for safety measure"
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn derive_wrld_desc(item: proc_macro::TokenStream, step_mode: crate::format::VertexStepMode) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_desc(input, step_mode)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_wrld_desc(input: syn::DeriveInput, step_mode: crate::format::VertexStepMode) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::Desc")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+
+    if let Err(e) = require_repr_c(&ident, &attrs) {
+        errors.push(e);
+    }
+
+    let derive = match step_mode {
+        crate::format::VertexStepMode::Vertex => "Desc",
+        crate::format::VertexStepMode::Instance => "DescInstance"
+    };
+
+    let wrld = get_wrld_attrs(&attrs, derive, &DESC_WRLD_OPTIONS, &mut errors);
+    let entity = get_entity(fields, &mut errors);
+    let resolved = resolve_attributes(&entity.fields, wrld.auto_location, &mut errors);
+    let shader_check = build_shader_check(&wrld, &resolved, &mut errors);
+
+    combine_errors(errors)?;
+
+    let wgpu = wrld.wgpu_path();
+    let attrs = build_vertex_attributes(&ident, &resolved, &wgpu);
+    let size_checks = build_size_checks(&resolved);
+    let location_consts = build_location_consts(&resolved);
+    let wgsl = build_wgsl_struct(&ident, &resolved);
+    let glsl = build_glsl_inputs(&resolved);
+    let hlsl = build_hlsl_struct(&ident, &resolved);
+    let step_mode = crate::parser::TokenVertexStepMode { step_mode, wgpu: wgpu.clone() };
+    let desc = desc_tokens(&ident, &attrs, quote::quote! { #step_mode }, &wgpu);
+
+    Ok(quote::quote! {
+        #(#size_checks)*
+        #shader_check
+
+        impl #ident {
+            #(#location_consts)*
+
+            /// WGSL vertex input structure matching the layout.
+            pub const WGSL: &'static str = #wgsl;
+
+            /// GLSL vertex input declarations matching the layout.
+            pub const GLSL: &'static str = #glsl;
+
+            /// HLSL vertex input structure matching the layout.
+            pub const HLSL: &'static str = #hlsl;
+        }
+
+        #desc
+    })
+}
+
+pub fn derive_wrld_buffer_data(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_buffer_data(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_wrld_buffer_data(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::BufferData")?;
+
+    // Attribute errors are reported by Desc / DescInstance, reporting them here would show them twice.
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let wrld = get_wrld_attrs(&attrs, "BufferData", &DESC_WRLD_OPTIONS, &mut errors);
+    let entity = get_entity(fields, &mut errors);
+
+    let subclass_name = quote::format_ident!("{}{}", ident, "BufferData");
+
+    let mut struct_fields : Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut equal_fields : Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut partial_eq_fields : Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut into_fields : Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut unpack_fields : Vec<proc_macro2::TokenStream> = Vec::new();
+
+    // Only the fields holding a vertex attribute are packed.
+    let packed_fields : Vec<&EntityFields> = entity.fields.iter().filter(|f| !f.attrs.is_empty()).collect();
+
+    packed_fields.iter().for_each(|f| {
+        let name = f.name.clone();
+        let ty = f.ty.clone();
+
+        struct_fields.push(quote::quote! {
+            #name: #ty
+        });
+
+        equal_fields.push(quote::quote! {
+            #name: other_data_from_ident_to_into.#name
+        });
+
+        partial_eq_fields.push(quote::quote! {
+            self.#name == other_ident_data_boolean_condition.#name
+        });
+
+        into_fields.push(quote::quote! {
+            #name: other_ident_data_to_into_const.#name
+        });
+
+        unpack_fields.push(quote::quote! {
+            dst_data_from_ident_to_unpack.#name = packed_data_from_bytes_to_unpack.#name;
+        });
+    });
+
+    let resolved = resolve_attributes(packed_fields.iter().copied(), wrld.auto_location, &mut errors);
+
+    if !errors.is_empty() {
+        return Ok(proc_macro2::TokenStream::new())
+    }
+
+    // A zero-sized packed structure has no stride to split the buffers with.
+    if packed_fields.is_empty() {
+        return Err(syn::Error::new(ident.span(), format!("wrld::BufferData needs at least one field with a vertex attribute in `{}`", ident)))
+    }
+
+    let private = private_path();
+    let wgpu = wrld.wgpu_path();
+    let bytemuck = wrld.bytemuck_path();
+    let bytemuck_path = bytemuck.to_string().replace(' ', "");
+    let packed_attrs = build_vertex_attributes(&subclass_name, &resolved, &wgpu);
+    let packed_desc = desc_tokens(&subclass_name, &packed_attrs, quote::quote! { <#ident as #private::VertexLayout>::STEP_MODE }, &wgpu);
+
+    let result = snake_case(&ident);
+
+    let const_into_macro = quote::format_ident!("{}_const_into", result);
+    let mutate_data_macro = quote::format_ident!("mutate_{}", result);
+
+    Ok(quote::quote! {
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, #bytemuck::Pod, #bytemuck::Zeroable)]
+        #[bytemuck(crate = #bytemuck_path)]
+        struct #subclass_name {
+            #(#struct_fields),*
+        }
+
+        #packed_desc
+
+        impl From<#ident> for #subclass_name {
+            fn from(other_data_from_ident_to_into: #ident) -> Self {
+                Self {
+                    #(#equal_fields),*
+                }
+            }
+        }
+
+        impl<'a> From<&'a #ident> for #subclass_name {
+            fn from(other_data_from_ident_to_into: &'a #ident) -> Self {
+                Self {
+                    #(#equal_fields),*
+                }
+            }
+        }
+
+        impl PartialEq<#ident> for #subclass_name {
+            fn eq(&self, other_ident_data_boolean_condition: &#ident) -> bool {
+                #(#partial_eq_fields)&&*
+            }
+        }
+
+        impl FromIterator<#ident> for Vec<#subclass_name> {
+            fn from_iter<T: IntoIterator<Item = #ident>>(iter: T) -> Self {
+                let mut vec_data_from_ident_from_iterator = Vec::new();
+
+                for c in iter {
+                    vec_data_from_ident_from_iterator.push(c.into());
+                }
+
+                vec_data_from_ident_from_iterator
+            }
+        }
+
+        impl<'a> FromIterator<&'a #ident> for Vec<#subclass_name> {
+            fn from_iter<T: IntoIterator<Item = &'a #ident>>(iter: T) -> Self {
+                let mut vec_data_from_ident_single_from_iterator : Vec<#subclass_name> = Vec::new();
+
+                for c in iter {
+                    vec_data_from_ident_single_from_iterator.push(c.into());
+                }
+
+                vec_data_from_ident_single_from_iterator
+            }
+        }
+
+        impl #subclass_name {
+            pub const fn const_into(other_ident_data_to_into_const: &#ident) -> Self {
+                Self {
+                    #(#into_fields),*
+                }
+            }
+        }
+
+        impl #ident {
+            pub fn mutate<'a>(other_data_from_ident_to_mutate: &'a Vec<#subclass_name>) -> &'a [u8] {
+                #bytemuck::cast_slice(other_data_from_ident_to_mutate.as_slice())
+            }
+
+            pub fn transmute(other_data_from_ident_to_transmute: &[Self]) -> Vec<#subclass_name> {
+                other_data_from_ident_to_transmute.iter().collect::<Vec<#subclass_name>>()
+            }
+
+            pub fn pack<'a>(other_data_from_ident_to_pack: impl IntoIterator<Item = &'a Self>) -> Vec<u8> {
+                let mut packed_bytes_from_ident = Vec::new();
+
+                for c in other_data_from_ident_to_pack {
+                    packed_bytes_from_ident.extend_from_slice(#bytemuck::bytes_of(&#subclass_name::from(c)));
+                }
+
+                packed_bytes_from_ident
+            }
+
+            pub const fn packed_len(count: usize) -> usize {
+                count * ::core::mem::size_of::<#subclass_name>()
+            }
+
+            pub fn write_packed(src: &[Self], dst: &mut [u8]) -> ::core::result::Result<usize, #private::BufferTooSmall> {
+                let required = Self::packed_len(src.len());
+
+                if dst.len() < required {
+                    return ::core::result::Result::Err(#private::BufferTooSmall { required, len: dst.len() });
+                }
+
+                let chunks = dst.chunks_exact_mut(::core::mem::size_of::<#subclass_name>());
+
+                for (c, chunk) in src.iter().zip(chunks) {
+                    chunk.copy_from_slice(#bytemuck::bytes_of(&#subclass_name::from(c)));
+                }
+
+                ::core::result::Result::Ok(required)
+            }
+
+            pub fn unpack_into(bytes: &[u8], dst: &mut [Self]) -> ::core::result::Result<(), #private::UnpackError> {
+                let stride = ::core::mem::size_of::<#subclass_name>();
+
+                if bytes.len() % stride != 0 {
+                    return ::core::result::Result::Err(#private::UnpackError::PartialRecord { len: bytes.len(), stride });
+                }
+
+                if bytes.len() / stride != dst.len() {
+                    return ::core::result::Result::Err(#private::UnpackError::CountMismatch { records: bytes.len() / stride, len: dst.len() });
+                }
+
+                for (dst_data_from_ident_to_unpack, chunk) in dst.iter_mut().zip(bytes.chunks_exact(stride)) {
+                    let packed_data_from_bytes_to_unpack: #subclass_name = #bytemuck::pod_read_unaligned(chunk);
+
+                    #(#unpack_fields)*
+                }
+
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        #[allow(unused_macros)]
+        macro_rules! #const_into_macro {
+            ($data: expr) => {
+                #subclass_name::const_into(&$data)
+            };
+        }
+        
+        #[allow(unused_macros)]
+        macro_rules! #mutate_data_macro {
+            ($data: expr) => {
+                #ident::mutate(&#ident::transmute($data))
+            };
+        }
+    })
+}
\ No newline at end of file
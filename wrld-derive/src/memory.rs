@@ -0,0 +1,426 @@
+//! Memory layout of the host-shareable types (uniform and storage buffers) following the WGSL rules.
+//!
+//! The uniform address space follow the std140 rules of WGSL : arrays are aligned on 16 bytes and
+//! their element stride must be a multiple of 16. The storage address space follow the std430 rules of WGSL.
+//!
+//! Nested structures are only known by the generated code, their layout come from the `wrld::Storage` trait
+//! and the layout of the structures holding them is computed in const expressions.
+
+/// Address space of the buffer holding the structure.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AddressSpace {
+    Uniform,
+    Storage,
+    /// Push constants (immediates with wgpu 30), the natural layout of WGSL without nested structures.
+    PushConstant
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Scalar {
+    F32,
+    U32,
+    I32
+}
+
+impl Scalar {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "f32" => Some(Scalar::F32),
+            "u32" => Some(Scalar::U32),
+            "i32" => Some(Scalar::I32),
+            _ => None
+        }
+    }
+
+    pub const fn wgsl_type(self) -> &'static str {
+        match self {
+            Scalar::F32 => "f32",
+            Scalar::U32 => "u32",
+            Scalar::I32 => "i32"
+        }
+    }
+
+    /// Value written in the padding components.
+    fn zero(self) -> proc_macro2::TokenStream {
+        match self {
+            Scalar::F32 => quote::quote! { 0.0f32 },
+            Scalar::U32 => quote::quote! { 0u32 },
+            Scalar::I32 => quote::quote! { 0i32 }
+        }
+    }
+
+    fn rust_type(self) -> proc_macro2::TokenStream {
+        match self {
+            Scalar::F32 => quote::quote! { f32 },
+            Scalar::U32 => quote::quote! { u32 },
+            Scalar::I32 => quote::quote! { i32 }
+        }
+    }
+}
+
+/// Host-shareable type of a field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShaderType {
+    Scalar(Scalar),
+    /// `[T; N]` with N in 2..=4.
+    Vector(Scalar, usize),
+    /// `[[f32; R]; C]` with C and R in 2..=4, column major.
+    Matrix { columns: usize, rows: usize },
+    Array(Box<ShaderType>, usize),
+    /// Structure implementing `wrld::Storage`.
+    Struct(syn::Type)
+}
+
+pub const fn round_up(align: u64, value: u64) -> u64 {
+    value.div_ceil(align) * align
+}
+
+/// Error of the field types that cannot be placed in a buffer, spanned on the type.
+fn not_host_shareable(ty: &syn::Type) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        "This type is not a host-shareable type, expected f32, u32, i32, vectors ([T; 2..=4]), matrices ([[f32; 2..=4]; 2..=4]), arrays of them or wrld::Storage structures in storage buffers"
+    )
+}
+
+impl ShaderType {
+    /// Shader type of a field placed in a buffer of the address space, checked against the rules of the address space.
+    pub fn from_type(ty: &syn::Type, space: AddressSpace) -> syn::Result<Self> {
+        let shader_type = ShaderType::parse(ty).ok_or_else(|| not_host_shareable(ty))?;
+
+        shader_type.check(ty, space)?;
+
+        Ok(shader_type)
+    }
+
+    /// Shader type of a rust type, the types that are not a scalar are structures.
+    ///
+    /// `[T; 2..=4]` is a vector, `[[f32; 2..=4]; 2..=4]` is a matrix and the other arrays are WGSL arrays.
+    pub fn parse(ty: &syn::Type) -> Option<Self> {
+        match ty {
+            syn::Type::Path(path) if path.qself.is_none() => {
+                let scalar = path.path.get_ident().and_then(|ident| Scalar::from_name(&ident.to_string()));
+
+                Some(scalar.map_or_else(|| ShaderType::Struct(ty.clone()), ShaderType::Scalar))
+            },
+            syn::Type::Array(array) => {
+                let len: usize = match &array.len {
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse().ok()?,
+                    _ => return None
+                };
+
+                let is_vector = (2..=4).contains(&len);
+
+                Some(match ShaderType::parse(&array.elem)? {
+                    ShaderType::Scalar(scalar) if is_vector => ShaderType::Vector(scalar, len),
+                    ShaderType::Vector(Scalar::F32, rows) if is_vector => ShaderType::Matrix { columns: len, rows },
+                    element => ShaderType::Array(Box::new(element), len)
+                })
+            },
+            syn::Type::Paren(paren) => ShaderType::parse(&paren.elem),
+            syn::Type::Group(group) => ShaderType::parse(&group.elem),
+            _ => None
+        }
+    }
+
+    pub fn wgsl_type(&self) -> String {
+        match self {
+            ShaderType::Scalar(scalar) => scalar.wgsl_type().to_string(),
+            ShaderType::Vector(scalar, size) => format!("vec{}<{}>", size, scalar.wgsl_type()),
+            ShaderType::Matrix { columns, rows } => format!("mat{}x{}<f32>", columns, rows),
+            ShaderType::Array(element, len) => format!("array<{}, {}>", element.wgsl_type(), len),
+            ShaderType::Struct(ty) => match ty {
+                syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default(),
+                _ => String::new()
+            }
+        }
+    }
+
+    pub fn has_struct(&self) -> bool {
+        match self {
+            ShaderType::Struct(_) => true,
+            ShaderType::Array(element, _) => element.has_struct(),
+            _ => false
+        }
+    }
+
+    /// Alignment of the type in WGSL, without the constraints of the address space.
+    ///
+    /// The layout of structures is only known by the generated code, see [`ShaderType::align_tokens`].
+    pub fn align(&self) -> u64 {
+        match self {
+            ShaderType::Scalar(_) => 4,
+            ShaderType::Vector(_, 2) => 8,
+            ShaderType::Vector(_, _) => 16,
+            ShaderType::Matrix { rows, .. } => ShaderType::Vector(Scalar::F32, *rows).align(),
+            ShaderType::Array(element, _) => element.align(),
+            ShaderType::Struct(_) => unreachable!("structure layout is only known by the generated code")
+        }
+    }
+
+    /// Alignment required in the address space, arrays of the uniform address space are aligned on 16 bytes.
+    pub fn required_align(&self, space: AddressSpace) -> u64 {
+        match (self, space) {
+            (ShaderType::Array(element, _), AddressSpace::Uniform) => round_up(16, element.required_align(space)),
+            _ => self.align()
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        match self {
+            ShaderType::Scalar(_) => 4,
+            ShaderType::Vector(_, size) => 4 * *size as u64,
+            ShaderType::Matrix { columns, rows } => {
+                let column = ShaderType::Vector(Scalar::F32, *rows);
+                *columns as u64 * round_up(column.align(), column.size())
+            },
+            ShaderType::Array(element, len) => *len as u64 * element.stride(),
+            ShaderType::Struct(_) => unreachable!("structure layout is only known by the generated code")
+        }
+    }
+
+    /// Distance between two elements of an array of this type.
+    pub fn stride(&self) -> u64 {
+        round_up(self.align(), self.size())
+    }
+
+    /// Check the constraints of the address space on the type, `ty` being the rust type the errors are spanned on.
+    /// Nested structures are only supported in storage buffers.
+    fn check(&self, ty: &syn::Type, space: AddressSpace) -> syn::Result<()> {
+        match (self, space) {
+            (ShaderType::Struct(_), AddressSpace::Uniform | AddressSpace::PushConstant) => Err(not_host_shareable(ty)),
+            (ShaderType::Array(element, _), AddressSpace::PushConstant) => element.check(ty, space),
+            (ShaderType::Array(element, _), AddressSpace::Uniform) => {
+                element.check(ty, space)?;
+
+                if element.stride() % 16 != 0 {
+                    return Err(syn::Error::new_spanned(ty, format!(
+                        "`{}` has an element stride of {} bytes, uniform arrays need a stride multiple of 16 (use vec4 elements)",
+                        self.wgsl_type(), element.stride()
+                    )));
+                }
+
+                Ok(())
+            },
+            _ => Ok(())
+        }
+    }
+
+    /// Rust type holding the type with its padding, `[[f32; 3]; 3]` become `[[f32; 4]; 3]`.
+    pub fn padded_type(&self) -> proc_macro2::TokenStream {
+        match self {
+            ShaderType::Scalar(scalar) => scalar.rust_type(),
+            ShaderType::Vector(scalar, size) => {
+                let scalar = scalar.rust_type();
+                quote::quote! { [#scalar; #size] }
+            },
+            ShaderType::Matrix { columns, rows } => {
+                let rows = if *rows == 3 { 4 } else { *rows };
+                quote::quote! { [[f32; #rows]; #columns] }
+            },
+            ShaderType::Array(element, len) => {
+                let element = match element.as_ref() {
+                    ShaderType::Vector(scalar, 3) => ShaderType::Vector(*scalar, 4).padded_type(),
+                    element => element.padded_type()
+                };
+
+                quote::quote! { [#element; #len] }
+            },
+            ShaderType::Struct(ty) => quote::quote! { #ty }
+        }
+    }
+
+    /// Does the padded type differ from the rust type.
+    fn is_padded(&self) -> bool {
+        match self {
+            ShaderType::Scalar(_) | ShaderType::Vector(_, _) | ShaderType::Struct(_) => false,
+            ShaderType::Matrix { rows, .. } => *rows == 3,
+            ShaderType::Array(element, _) => matches!(element.as_ref(), ShaderType::Vector(_, 3)) || element.is_padded()
+        }
+    }
+
+    /// Expression converting `value` from the rust type to the padded type.
+    pub fn pad(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if !self.is_padded() {
+            return value
+        }
+
+        match self {
+            ShaderType::Matrix { .. } => quote::quote! {
+                #value.map(|column| [column[0], column[1], column[2], 0.0f32])
+            },
+            ShaderType::Array(element, _) => {
+                let padded = match element.as_ref() {
+                    ShaderType::Vector(scalar, 3) => {
+                        let zero = scalar.zero();
+                        quote::quote! { [element[0], element[1], element[2], #zero] }
+                    },
+                    element => element.pad(quote::quote! { element })
+                };
+
+                quote::quote! { #value.map(|element| #padded) }
+            },
+            _ => value
+        }
+    }
+
+    /// Const expression of the alignment, `<T as Storage>::ALIGN` for structures.
+    pub fn align_tokens(&self, private: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            ShaderType::Struct(ty) => quote::quote! { <#ty as #private::Storage>::ALIGN },
+            ShaderType::Array(element, _) => element.align_tokens(private),
+            _ => {
+                let align = self.align();
+                quote::quote! { #align }
+            }
+        }
+    }
+
+    /// Const expression of the size, `<T as Storage>::SIZE` for structures.
+    pub fn size_tokens(&self, private: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            ShaderType::Struct(ty) => quote::quote! { <#ty as #private::Storage>::SIZE },
+            ShaderType::Array(element, len) if element.has_struct() => {
+                let stride = element.stride_tokens(private);
+                let len = *len as u64;
+                quote::quote! { #len * #stride }
+            },
+            _ => {
+                let size = self.size();
+                quote::quote! { #size }
+            }
+        }
+    }
+
+    /// Const expression of the array element stride.
+    pub fn stride_tokens(&self, private: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let align = self.align_tokens(private);
+        let size = self.size_tokens(private);
+
+        quote::quote! { #private::round_up(#align, #size) }
+    }
+
+    /// Statements writing `value` at `offset` of the `dst` byte slice.
+    pub fn write_tokens(
+        &self,
+        private: &proc_macro2::TokenStream,
+        bytemuck: &proc_macro2::TokenStream,
+        value: proc_macro2::TokenStream,
+        offset: proc_macro2::TokenStream,
+        depth: usize
+    ) -> proc_macro2::TokenStream {
+        match self {
+            ShaderType::Struct(ty) => quote::quote! {
+                <#ty as #private::Storage>::write_storage(&#value, &mut dst[#offset as usize..(#offset + <#ty as #private::Storage>::SIZE) as usize]);
+            },
+            ShaderType::Array(element, _) if self.has_struct() => {
+                let index = quote::format_ident!("index_{}", depth);
+                let item = quote::format_ident!("element_{}", depth);
+                let stride = element.stride_tokens(private);
+                let write = element.write_tokens(
+                    private, bytemuck, quote::quote! { #item }, quote::quote! { (#offset + #index as u64 * #stride) }, depth + 1
+                );
+
+                quote::quote! {
+                    for (#index, #item) in #value.iter().enumerate() {
+                        #write
+                    }
+                }
+            },
+            _ => {
+                let size = self.size();
+                let padded = self.pad(value);
+
+                quote::quote! {
+                    dst[#offset as usize..(#offset + #size) as usize].copy_from_slice(#bytemuck::bytes_of(&#padded));
+                }
+            }
+        }
+    }
+}
+
+/// Field of a host-shareable structure placed at its offset.
+pub struct MemberLayout {
+    pub name: proc_macro2::Ident,
+    pub ty: ShaderType,
+    pub offset: u64,
+    /// Alignment required by the address space when it is bigger than the alignment of the type, written as `@align(N)`.
+    pub align_attribute: Option<u64>
+}
+
+/// Host-shareable structure, members are in the order of the rust structure.
+pub struct StructLayout {
+    pub members: Vec<MemberLayout>,
+    pub size: u64
+}
+
+impl StructLayout {
+    pub fn new(fields: Vec<(proc_macro2::Ident, ShaderType)>, space: AddressSpace) -> Self {
+        let mut members = Vec::new();
+        let mut offset = 0;
+        let mut align = 1;
+
+        for (name, ty) in fields {
+            let required = ty.required_align(space);
+            offset = round_up(required, offset);
+            align = align.max(required);
+
+            members.push(MemberLayout {
+                name,
+                offset,
+                align_attribute: (required > ty.align()).then_some(required),
+                ty
+            });
+
+            offset += members.last().map_or(0, |m| m.ty.size());
+        }
+
+        StructLayout { members, size: round_up(align, offset) }
+    }
+
+    /// Write the WGSL structure, named as `target`.
+    pub fn wgsl_struct(&self, target: &syn::Ident) -> String {
+        let mut wgsl = format!("struct {} {{\n", target);
+
+        for member in &self.members {
+            let name = member.name.to_string();
+            let name = name.trim_start_matches("r#");
+
+            match member.align_attribute {
+                Some(align) => wgsl.push_str(&format!("    @align({}) {}: {},\n", align, name, member.ty.wgsl_type())),
+                None => wgsl.push_str(&format!("    {}: {},\n", name, member.ty.wgsl_type()))
+            }
+        }
+
+        wgsl.push_str("}\n");
+        wgsl
+    }
+
+    /// Fields of the padded rust structure, with `_pad<N>: [u8; N]` fields filling the gaps.
+    pub fn padded_fields(&self, vis: impl Fn(&proc_macro2::Ident) -> syn::Visibility) -> Vec<proc_macro2::TokenStream> {
+        let mut fields = Vec::new();
+        let mut offset = 0;
+
+        let pad = |fields: &mut Vec<proc_macro2::TokenStream>, from: u64, to: u64| {
+            if to > from {
+                let name = quote::format_ident!("_pad{}", fields.len());
+                let len = (to - from) as usize;
+                fields.push(quote::quote! { #name: [u8; #len] });
+            }
+        };
+
+        for member in &self.members {
+            pad(&mut fields, offset, member.offset);
+
+            let name = &member.name;
+            let ty = member.ty.padded_type();
+            let vis = vis(name);
+            fields.push(quote::quote! { #vis #name: #ty });
+
+            offset = member.offset + member.ty.size();
+        }
+
+        pad(&mut fields, offset, self.size);
+        fields
+    }
+}
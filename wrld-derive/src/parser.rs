@@ -0,0 +1,378 @@
+use proc_macro2::{Ident, Span};
+
+/// Path of the runtime re-exports of wrld used by the generated code, so that users
+/// do not need wgpu or bytemuck as direct dependencies.
+pub fn private_path() -> proc_macro2::TokenStream {
+    quote::quote! { ::wrld::__private }
+}
+
+pub struct TokenVertexFormat {
+    pub attribute: crate::format::VertexFormat,
+    pub wgpu: proc_macro2::TokenStream
+}
+
+impl quote::ToTokens for TokenVertexFormat {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let wgpu = &self.wgpu;
+        let format = Ident::new(self.attribute.name(), Span::call_site());
+
+        tokens.extend(quote::quote! { #wgpu::VertexFormat::#format });
+    }
+}
+
+pub struct TokenVertexStepMode {
+    pub step_mode: crate::format::VertexStepMode,
+    pub wgpu: proc_macro2::TokenStream
+}
+
+impl quote::ToTokens for TokenVertexStepMode {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let wgpu = &self.wgpu;
+        let step_mode = Ident::new(self.step_mode.name(), Span::call_site());
+
+        tokens.extend(quote::quote! { #wgpu::VertexStepMode::#step_mode });
+    }
+}
+
+pub struct AttrData<'a> {
+    pub attribute: &'a syn::Attribute,
+    pub segment: &'a syn::PathSegment
+}
+
+pub fn parse_attrs<'a>(attrs : &'a [syn::Attribute], mut callback: Box<dyn FnMut(AttrData) + 'a>) {
+    attrs.iter().for_each(|a| {
+        a.path.segments.iter().for_each(|ps| {
+            callback(AttrData {
+                attribute: a,
+                segment: ps,
+            });
+        });
+    });
+}
+
+/// Attribute written without arguments, like `#[f32x2]`.
+pub fn is_bare_attr(attribute: &syn::Attribute) -> bool {
+    attribute.tokens.is_empty()
+}
+
+#[derive(Debug, Clone)]
+pub struct AttrMat {
+    pub ident: syn::Ident,
+    pub data: Option<u32>
+}
+
+impl syn::parse::Parse for AttrMat {
+    fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident : syn::Ident = tokens.parse()?;
+
+        // The location can be omitted with #[wrld(auto_location)]
+        if tokens.is_empty() {
+            return Ok(AttrMat { ident, data: None });
+        }
+
+        tokens.parse::<syn::Token![,]>()?;
+        let data : syn::LitInt = tokens.parse()?;
+
+        Ok(AttrMat { ident, data: Some(data.base10_parse()?) })
+    }
+}
+
+/// `#[vertex(location = 0)]` field attribute, the vertex format is inferred from the field type.
+///
+/// The location can be omitted with #[wrld(auto_location)], `#[vertex]`.
+#[derive(Debug, Clone)]
+pub struct AttrVertex {
+    pub location: Option<u32>
+}
+
+impl syn::parse::Parse for AttrVertex {
+    fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
+        let key : syn::Ident = tokens.parse()?;
+
+        if key != "location" {
+            return Err(syn::Error::new(key.span(), "Expected `location = <shader location>`"));
+        }
+
+        tokens.parse::<syn::Token![=]>()?;
+        let data : syn::LitInt = tokens.parse()?;
+
+        Ok(AttrVertex { location: Some(data.base10_parse()?) })
+    }
+}
+
+/// Value of a binding attribute option, `flag`, `key = "value"` or `key = Path`.
+#[derive(Debug, Clone)]
+pub enum BindingOption {
+    Flag,
+    Str(syn::LitStr),
+    Path(syn::Path)
+}
+
+/// `#[uniform(0, visibility = "vertex", dynamic)]` field attribute of BindGroup, the binding index followed by options.
+#[derive(Debug, Clone)]
+pub struct AttrBinding {
+    pub binding: u32,
+    pub options: Vec<(syn::Ident, BindingOption)>
+}
+
+impl syn::parse::Parse for AttrBinding {
+    fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
+        let binding = tokens.parse::<syn::LitInt>()
+            .map_err(|e| syn::Error::new(e.span(), "Expected the binding index"))?
+            .base10_parse()?;
+
+        let options = parse_binding_options(tokens)?;
+
+        Ok(AttrBinding { binding, options })
+    }
+}
+
+/// Parse the `, key = value` options following the first arguments of an attribute.
+fn parse_binding_options(tokens: syn::parse::ParseStream) -> syn::Result<Vec<(syn::Ident, BindingOption)>> {
+    let mut options = Vec::new();
+
+    while !tokens.is_empty() {
+        tokens.parse::<syn::Token![,]>()?;
+
+        if tokens.is_empty() {
+            break
+        }
+
+        let key : syn::Ident = tokens.parse()?;
+
+        let value = if tokens.peek(syn::Token![=]) {
+            tokens.parse::<syn::Token![=]>()?;
+
+            if tokens.peek(syn::LitStr) {
+                BindingOption::Str(tokens.parse()?)
+            } else {
+                BindingOption::Path(tokens.parse()?)
+            }
+        } else {
+            BindingOption::Flag
+        };
+
+        options.push((key, value));
+    }
+
+    Ok(options)
+}
+
+/// `#[target(0, Rgba8UnormSrgb, blend = alpha)]` field attribute of FragmentOutput, the color target index
+/// and its `wgpu::TextureFormat` followed by options.
+#[derive(Debug, Clone)]
+pub struct AttrTarget {
+    pub index: u32,
+    pub format: syn::Ident,
+    pub options: Vec<(syn::Ident, BindingOption)>
+}
+
+impl syn::parse::Parse for AttrTarget {
+    fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
+        let index = tokens.parse::<syn::LitInt>()
+            .map_err(|e| syn::Error::new(e.span(), "Expected the color target index"))?
+            .base10_parse()?;
+
+        tokens.parse::<syn::Token![,]>()
+            .map_err(|e| syn::Error::new(e.span(), "Expected the texture format, like `Rgba8UnormSrgb`"))?;
+
+        let format : syn::Ident = tokens.parse()
+            .map_err(|e| syn::Error::new(e.span(), "Expected the texture format, like `Rgba8UnormSrgb`"))?;
+
+        let options = parse_binding_options(tokens)?;
+
+        Ok(AttrTarget { index, format, options })
+    }
+}
+
+/// `#[constant(id = 0, default = 1.0)]` field attribute of PipelineConstants, both options are optional.
+#[derive(Debug, Clone, Default)]
+pub struct AttrConstant {
+    pub id: Option<syn::LitInt>,
+    /// Literal, negative literal for signed values.
+    pub default: Option<syn::Expr>
+}
+
+impl syn::parse::Parse for AttrConstant {
+    fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut constant = AttrConstant::default();
+
+        while !tokens.is_empty() {
+            let key : syn::Ident = tokens.parse()?;
+            tokens.parse::<syn::Token![=]>()?;
+
+            if key == "id" {
+                let lit : syn::LitInt = tokens.parse()?;
+                lit.base10_parse::<u16>()?;
+
+                constant.id = Some(lit);
+            } else if key == "default" {
+                constant.default = Some(tokens.parse()?);
+            } else {
+                return Err(syn::Error::new(key.span(), format!("Unknown option `{}` for #[constant], expected id or default", key)));
+            }
+
+            if !tokens.is_empty() {
+                tokens.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(constant)
+    }
+}
+
+/// Options of the `#[wrld(...)]` struct attribute.
+const WRLD_OPTIONS: [&str; 8] = ["auto_location", "wgpu", "bytemuck", "shader", "entry", "input", "visibility", "max_push_constant_size"];
+
+/// `#[wrld(...)]` struct attribute.
+///
+/// - `auto_location` or `auto_location = <base>` : assign shader locations in field order, starting from `base` (0 by default)
+/// - `wgpu = "<path>"` / `bytemuck = "<path>"` : path of the crates used by the generated code, `::wrld::__private::wgpu` and `::wrld::__private::bytemuck` by default
+/// - `shader = "<file>"`, `entry = "<name>"`, `input = "<argument>"` : WGSL shader, vertex entry point and entry point argument the structure is checked against
+/// - `visibility = "vertex | fragment"` : default shader stages of the BindGroup bindings, shader stages of the PushConstants range
+/// - `max_push_constant_size = <bytes>` : size limit of the PushConstants structure, 128 by default
+///
+/// Each derive only accept the options it use.
+#[derive(Debug, Clone, Default)]
+pub struct AttrWrld {
+    pub auto_location: Option<u32>,
+    pub wgpu: Option<syn::Path>,
+    pub bytemuck: Option<syn::Path>,
+    pub shader: Option<syn::LitStr>,
+    pub entry: Option<syn::LitStr>,
+    pub input: Option<syn::LitStr>,
+    pub visibility: Option<syn::LitStr>,
+    pub max_push_constant_size: Option<syn::LitInt>
+}
+
+impl AttrWrld {
+    fn parse_option(&mut self, tokens: syn::parse::ParseStream) -> syn::Result<()> {
+        let key : syn::Ident = tokens.parse()?;
+
+        if key == "auto_location" {
+            let mut base = 0;
+
+            if tokens.peek(syn::Token![=]) {
+                tokens.parse::<syn::Token![=]>()?;
+                base = tokens.parse::<syn::LitInt>()?.base10_parse()?;
+            }
+
+            self.auto_location = Some(base);
+            return Ok(());
+        }
+
+        if key == "max_push_constant_size" {
+            tokens.parse::<syn::Token![=]>()?;
+            let lit : syn::LitInt = tokens.parse()?;
+            lit.base10_parse::<u32>()?;
+
+            self.max_push_constant_size = Some(lit);
+            return Ok(());
+        }
+
+        if key == "wgpu" || key == "bytemuck" {
+            tokens.parse::<syn::Token![=]>()?;
+            let lit : syn::LitStr = tokens.parse()?;
+            let path = lit.parse::<syn::Path>()
+                .map_err(|_| syn::Error::new(lit.span(), format!("`{}` is not a valid path for {}", lit.value(), key)))?;
+
+            if key == "wgpu" {
+                self.wgpu = Some(path);
+            } else {
+                self.bytemuck = Some(path);
+            }
+
+            return Ok(());
+        }
+
+        if key == "shader" || key == "entry" || key == "input" || key == "visibility" {
+            tokens.parse::<syn::Token![=]>()?;
+            let lit : syn::LitStr = tokens.parse()?;
+
+            if key == "shader" {
+                self.shader = Some(lit);
+            } else if key == "entry" {
+                self.entry = Some(lit);
+            } else if key == "input" {
+                self.input = Some(lit);
+            } else {
+                self.visibility = Some(lit);
+            }
+
+            return Ok(());
+        }
+
+        Err(syn::Error::new(key.span(), format!("Unknown wrld option `{}`", key)))
+    }
+
+    /// Path of the wgpu crate used by the generated code.
+    ///
+    /// wrld does not re-export wgpu 0.13, with `wgpu-0_13` the default is the `wgpu` crate of the user.
+    pub fn wgpu_path(&self) -> proc_macro2::TokenStream {
+        match &self.wgpu {
+            Some(path) => quote::quote! { #path },
+            None if crate::format::WgpuVersion::selected() == crate::format::WgpuVersion::V0_13 => quote::quote! { ::wgpu },
+            None => {
+                let private = private_path();
+                quote::quote! { #private::wgpu }
+            }
+        }
+    }
+
+    /// Path of the bytemuck crate used by the generated code.
+    pub fn bytemuck_path(&self) -> proc_macro2::TokenStream {
+        match &self.bytemuck {
+            Some(path) => quote::quote! { #path },
+            None => {
+                let private = private_path();
+                quote::quote! { #private::bytemuck }
+            }
+        }
+    }
+
+    /// Merge a `#[wrld(...)]` attribute into the options, the known options missing from `supported` are errors.
+    pub fn parse_into(&mut self, attribute: &syn::Attribute, derive: &str, supported: &[&str]) -> syn::Result<()> {
+        attribute.parse_args_with(|tokens: syn::parse::ParseStream| {
+            while !tokens.is_empty() {
+                let key : syn::Ident = tokens.fork().parse()?;
+
+                if WRLD_OPTIONS.iter().any(|option| key == option) && !supported.iter().any(|option| key == option) {
+                    return Err(syn::Error::new(key.span(), format!("Option `{}` is not supported by #[derive({})]", key, derive)));
+                }
+
+                self.parse_option(tokens)?;
+
+                if !tokens.is_empty() {
+                    tokens.parse::<syn::Token![,]>()?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Split a field type into its scalar type name and its array lengths, outermost first.
+///
+/// `[[f32; 4]; 3]` gives `("f32", [3, 4])`, `u32` gives `("u32", [])`.
+pub fn type_dimensions(ty: &syn::Type) -> Option<(String, Vec<usize>)> {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            Some((path.path.get_ident()?.to_string(), Vec::new()))
+        },
+        syn::Type::Array(array) => {
+            let len = match &array.len {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse().ok()?,
+                _ => return None
+            };
+
+            let (scalar, mut dims) = type_dimensions(&array.elem)?;
+            dims.insert(0, len);
+
+            Some((scalar, dims))
+        },
+        syn::Type::Paren(paren) => type_dimensions(&paren.elem),
+        syn::Type::Group(group) => type_dimensions(&group.elem),
+        _ => None
+    }
+}
@@ -0,0 +1,163 @@
+use crate::macros::{combine_errors, get_named_fields};
+use crate::parser::{parse_attrs, AttrConstant};
+
+pub fn derive_wrld_pipeline_constants(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_pipeline_constants(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Scalar types of WGSL override declarations.
+const OVERRIDE_TYPES: [&str; 4] = ["bool", "f32", "i32", "u32"];
+
+/// Override declaration of a field.
+struct Constant {
+    field: proc_macro2::Ident,
+    /// Key of the pipeline constant, the `@id` when present, the name otherwise.
+    key: String,
+    wgsl: String
+}
+
+/// WGSL literal of a `default` value, checked against the WGSL type.
+fn default_literal(ty: &str, default: &syn::Expr) -> syn::Result<String> {
+    let (negative, lit) = match default {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => (false, lit),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match expr.as_ref() {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => (true, lit),
+            _ => return Err(syn::Error::new_spanned(default, "Expected a literal"))
+        },
+        _ => return Err(syn::Error::new_spanned(default, "Expected a literal"))
+    };
+
+    let sign = if negative { "-" } else { "" };
+    let article = if ty == "i32" { "an" } else { "a" };
+    let invalid = || syn::Error::new_spanned(default, format!("Expected {} {} literal", article, ty));
+
+    match (ty, lit) {
+        ("bool", syn::Lit::Bool(lit)) if !negative => Ok(lit.value.to_string()),
+        ("f32", syn::Lit::Float(lit)) => {
+            lit.base10_parse::<f32>()?;
+            Ok(format!("{}{}", sign, lit.base10_digits()))
+        },
+        ("f32", syn::Lit::Int(lit)) => {
+            lit.base10_parse::<f32>()?;
+            Ok(format!("{}{}.0", sign, lit.base10_digits()))
+        },
+        ("i32", syn::Lit::Int(lit)) => {
+            let value = format!("{}{}", sign, lit.base10_digits());
+            value.parse::<i32>().map_err(|_| syn::Error::new_spanned(default, "The value does not fit in an i32"))?;
+            Ok(value)
+        },
+        ("u32", syn::Lit::Int(lit)) if !negative => {
+            lit.base10_parse::<u32>()?;
+            Ok(format!("{}u", lit.base10_digits()))
+        },
+        _ => Err(invalid())
+    }
+}
+
+fn expand_wrld_pipeline_constants(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, generics, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::PipelineConstants")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let mut constants: Vec<Constant> = Vec::new();
+
+    for field in fields {
+        let Some(name) = field.ident.clone() else { continue };
+        let field_name = name.to_string();
+        let field_name = field_name.trim_start_matches("r#");
+        let mut attributes: Vec<syn::Attribute> = Vec::new();
+
+        parse_attrs(&field.attrs, Box::new(|attr| {
+            if attr.segment.ident == "constant" {
+                attributes.push(attr.attribute.clone());
+            }
+        }));
+
+        if attributes.len() > 1 {
+            errors.push(syn::Error::new_spanned(&attributes[1], "A field can only have one #[constant]"));
+        }
+
+        let ty = &field.ty;
+        let wgsl_type = match ty {
+            syn::Type::Path(path) if path.qself.is_none() => path.path.get_ident()
+                .map(|ident| ident.to_string())
+                .filter(|ty| OVERRIDE_TYPES.contains(&ty.as_str())),
+            _ => None
+        };
+
+        let Some(wgsl_type) = wgsl_type else {
+            errors.push(syn::Error::new_spanned(ty, "This type cannot be a pipeline constant, expected bool, f32, i32 or u32"));
+            continue
+        };
+
+        let constant = match attributes.first() {
+            Some(attribute) => attribute.parse_args::<AttrConstant>(),
+            None => Ok(AttrConstant::default())
+        };
+
+        let constant = constant.and_then(|constant| {
+            let default = constant.default.as_ref().map(|default| default_literal(&wgsl_type, default)).transpose()?;
+            let id = constant.id.as_ref().map(|id| id.base10_parse::<u16>()).transpose()?;
+
+            if let Some(other) = id.and_then(|id| constants.iter().find(|c| c.key == id.to_string())) {
+                return Err(syn::Error::new_spanned(
+                    &constant.id,
+                    format!("Pipeline constant id {} is already used by `{}`", other.key, other.field)
+                ));
+            }
+
+            let mut wgsl = match id {
+                Some(id) => format!("@id({}) override {}: {}", id, field_name, wgsl_type),
+                None => format!("override {}: {}", field_name, wgsl_type)
+            };
+
+            if let Some(default) = default {
+                wgsl.push_str(&format!(" = {}", default));
+            }
+
+            wgsl.push_str(";\n");
+
+            Ok(Constant {
+                field: name.clone(),
+                key: id.map_or_else(|| field_name.to_string(), |id| id.to_string()),
+                wgsl
+            })
+        });
+
+        match constant {
+            Ok(constant) => constants.push(constant),
+            Err(e) => errors.push(e)
+        }
+    }
+
+    combine_errors(errors)?;
+
+    let wgsl: String = constants.iter().map(|constant| constant.wgsl.as_str()).collect();
+    let count = constants.len();
+
+    let entries = constants.iter().map(|Constant { field, key, .. }| quote::quote! {
+        (#key, <f64 as ::core::convert::From<_>>::from(self.#field))
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote::quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// WGSL override declarations of the constants.
+            pub const WGSL: &'static str = #wgsl;
+
+            /// Pipeline constants of the values, keyed by `@id` or by name.
+            pub fn constants(&self) -> ::std::collections::HashMap<::std::string::String, f64> {
+                self.constant_entries().into_iter().map(|(key, value)| (::std::string::String::from(key), value)).collect()
+            }
+
+            /// Pipeline constants of the values as `(key, value)` pairs, for the `constants` of the pipeline compilation options.
+            pub fn constant_entries(&self) -> [(&'static str, f64); #count] {
+                [#(#entries),*]
+            }
+        }
+    })
+}
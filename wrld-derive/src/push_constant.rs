@@ -0,0 +1,110 @@
+use crate::bind_group::shader_stages;
+use crate::format::WgpuVersion;
+use crate::macros::{combine_errors, get_entity, get_named_fields, get_shader_fields, get_wrld_attrs, snake_case};
+use crate::memory::{AddressSpace, StructLayout};
+use crate::parser::private_path;
+
+/// Push constant size guaranteed by every backend supporting them.
+const DEFAULT_MAX_PUSH_CONSTANT_SIZE: u64 = 128;
+
+pub fn derive_wrld_push_constants(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_push_constants(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_wrld_push_constants(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::PushConstants")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let wrld = get_wrld_attrs(&attrs, "PushConstants", &["wgpu", "bytemuck", "visibility", "max_push_constant_size"], &mut errors);
+    let entity = get_entity(fields, &mut errors);
+    let members = get_shader_fields(&entity.fields, AddressSpace::PushConstant, &mut errors);
+
+    let wgpu = wrld.wgpu_path();
+
+    let stages = match &wrld.visibility {
+        Some(stages) => shader_stages(stages, &wgpu).unwrap_or_else(|e| {
+            errors.push(e);
+            proc_macro2::TokenStream::new()
+        }),
+        None => quote::quote! { #wgpu::ShaderStages::VERTEX_FRAGMENT }
+    };
+
+    combine_errors(errors)?;
+
+    let layout = StructLayout::new(members, AddressSpace::PushConstant);
+    let align = layout.members.iter().map(|m| m.ty.align()).max().unwrap_or(4);
+
+    let max_size = match &wrld.max_push_constant_size {
+        Some(lit) => lit.base10_parse()?,
+        None => DEFAULT_MAX_PUSH_CONSTANT_SIZE
+    };
+
+    if layout.size > max_size {
+        let span = wrld.max_push_constant_size.as_ref().map_or(ident.span(), |lit| lit.span());
+
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "`{}` is {} bytes, more than the push constant limit of {} bytes (`#[wrld(max_push_constant_size = ...)]`)",
+                ident, layout.size, max_size
+            )
+        ));
+    }
+
+    let private = private_path();
+    let bytemuck = wrld.bytemuck_path();
+    let size = layout.size as u32;
+    let align = align as u32;
+
+    let writes = layout.members.iter().map(|member| {
+        let name = &member.name;
+        let offset = member.offset;
+
+        member.ty.write_tokens(&private, &bytemuck, quote::quote! { self.#name }, quote::quote! { #offset }, 0)
+    });
+
+    // wgpu 0.13 and 0.19 declare a push constant range per shader stage, wgpu 30 names them immediates and only need the size.
+    let (address_space, range) = match WgpuVersion::selected() {
+        WgpuVersion::V0_13 | WgpuVersion::V0_19 => ("push_constant", quote::quote! {
+            /// Push constant range of the structure, for the `push_constant_ranges` of the pipeline layout.
+            pub const PUSH_CONSTANT_RANGE: #wgpu::PushConstantRange = #wgpu::PushConstantRange {
+                stages: #stages,
+                range: 0..#size
+            };
+        }),
+        WgpuVersion::Latest => ("immediate", quote::quote! {
+            /// Shader stages reading the structure.
+            pub const STAGES: #wgpu::ShaderStages = #stages;
+        })
+    };
+
+    let wgsl = format!("{}var<{}> {}: {};\n", layout.wgsl_struct(&ident), address_space, snake_case(&ident), ident);
+
+    Ok(quote::quote! {
+        impl #ident {
+            /// Size of the structure in bytes, the `immediate_size` of the pipeline layout with wgpu 30.
+            pub const SIZE: u32 = #size;
+
+            /// Alignment of the structure in bytes.
+            pub const ALIGN: u32 = #align;
+
+            /// WGSL structure and push constant variable matching the layout.
+            pub const WGSL: &'static str = #wgsl;
+
+            #range
+
+            /// Bytes of the structure following the WGSL layout, for `set_push_constants` (`set_immediates` with wgpu 30).
+            pub fn bytes(&self) -> [u8; #size as usize] {
+                let mut dst = [0u8; #size as usize];
+
+                #(#writes)*
+
+                dst
+            }
+        }
+    })
+}
@@ -0,0 +1,310 @@
+//! WGSL shaders handling with naga :
+//! - validation of a structure against a vertex entry point, `#[wrld(shader = "...", entry = "...")]`
+//! - structure generation from a vertex entry point, `vertex_from_wgsl!`
+
+use crate::format::VertexFormat;
+
+/// Vertex attribute of the structure as read by the shader, one per shader location.
+#[cfg_attr(not(feature = "shader"), allow(dead_code))]
+pub struct ShaderAttribute {
+    pub location: u32,
+    pub format: VertexFormat,
+    pub field: proc_macro2::Ident
+}
+
+/// Check every shader location of the entry point against the attributes of the structure.
+///
+/// Returns the tokens making the crate rebuild when the shader file change.
+#[cfg(not(feature = "shader"))]
+pub fn check_shader(
+    shader: &syn::LitStr,
+    _entry: Option<&syn::LitStr>,
+    _input: Option<&syn::LitStr>,
+    _attributes: &[ShaderAttribute],
+    errors: &mut Vec<syn::Error>
+) -> proc_macro2::TokenStream {
+    errors.push(syn::Error::new(shader.span(), "#[wrld(shader = \"...\")] require the `shader` feature of wrld"));
+    proc_macro2::TokenStream::new()
+}
+
+/// Shader loaded from a path relative to the crate root, like the other cargo paths.
+#[cfg(feature = "shader")]
+struct Shader {
+    module: naga::Module,
+    /// Tokens making the crate rebuild when the shader file change.
+    rebuild: proc_macro2::TokenStream
+}
+
+#[cfg(feature = "shader")]
+fn load_shader(shader: &syn::LitStr) -> syn::Result<Shader> {
+    let path = std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join(shader.value());
+
+    let source = std::fs::read_to_string(&path).map_err(|e| {
+        syn::Error::new(shader.span(), format!("Cannot read shader `{}` : {}", path.display(), e))
+    })?;
+
+    let module = naga::front::wgsl::parse_str(&source).map_err(|e| {
+        syn::Error::new(
+            shader.span(),
+            format!("Cannot parse shader `{}` :\n{}", shader.value(), e.emit_to_string_with_path(&source, &shader.value()))
+        )
+    })?;
+
+    let path_str = path.display().to_string();
+
+    Ok(Shader {
+        module,
+        rebuild: quote::quote! {
+            const _: &str = ::core::include_str!(#path_str);
+        }
+    })
+}
+
+/// Shader input read at a location by a vertex entry point.
+#[cfg(feature = "shader")]
+struct ShaderInput {
+    name: String,
+    location: u32,
+    ty: naga::Handle<naga::Type>
+}
+
+/// Find the vertex entry point (the only one when `entry` is not given) and its location inputs,
+/// from its arguments and the members of its structure arguments.
+///
+/// With `input`, only the locations of this argument of the entry point are returned, for entry points reading multiple buffers.
+#[cfg(feature = "shader")]
+fn entry_inputs<'a>(
+    module: &'a naga::Module,
+    shader: &syn::LitStr,
+    entry: Option<&syn::LitStr>,
+    input: Option<&syn::LitStr>
+) -> syn::Result<(&'a naga::EntryPoint, Vec<ShaderInput>)> {
+    let vertex_entries: Vec<&naga::EntryPoint> = module.entry_points.iter()
+        .filter(|e| e.stage == naga::ShaderStage::Vertex)
+        .collect();
+
+    let entry_point = match entry {
+        Some(entry) => vertex_entries.iter().find(|e| e.name == entry.value()).copied().ok_or_else(|| {
+            syn::Error::new(entry.span(), format!("No vertex entry point `{}` in `{}`", entry.value(), shader.value()))
+        })?,
+        None => match vertex_entries.as_slice() {
+            [entry_point] => *entry_point,
+            _ => return Err(syn::Error::new(
+                shader.span(),
+                format!(
+                    "`{}` has {} vertex entry points, select one with `entry = \"...\"`",
+                    shader.value(), vertex_entries.len()
+                )
+            ))
+        }
+    };
+
+    let arguments: Vec<&naga::FunctionArgument> = entry_point.function.arguments.iter()
+        .filter(|argument| input.is_none_or(|input| argument.name.as_deref() == Some(input.value().as_str())))
+        .collect();
+
+    if let (Some(input), []) = (input, arguments.as_slice()) {
+        return Err(syn::Error::new(input.span(), format!("`{}` has no argument `{}`", entry_point.name, input.value())));
+    }
+
+    let mut inputs: Vec<ShaderInput> = Vec::new();
+
+    for argument in arguments {
+        let name = argument.name.clone().unwrap_or_default();
+
+        match (&argument.binding, &module.types[argument.ty].inner) {
+            (Some(naga::Binding::Location { location, .. }), _) => inputs.push(ShaderInput { name, location: *location, ty: argument.ty }),
+            (None, naga::TypeInner::Struct { members, .. }) => {
+                for member in members {
+                    if let Some(naga::Binding::Location { location, .. }) = member.binding {
+                        inputs.push(ShaderInput { name: member.name.clone().unwrap_or_default(), location, ty: member.ty });
+                    }
+                }
+            },
+            _ => ()
+        }
+    }
+
+    Ok((entry_point, inputs))
+}
+
+/// Scalar kind, scalar width and vector size of a scalar or vector shader input, `None` if it is not a number.
+#[cfg(feature = "shader")]
+fn input_scalar(module: &naga::Module, ty: naga::Handle<naga::Type>) -> Option<(crate::format::ScalarKind, u8, Option<u8>)> {
+    use crate::format::ScalarKind;
+
+    let (scalar, size) = match module.types[ty].inner {
+        naga::TypeInner::Scalar(scalar) => (scalar, None),
+        naga::TypeInner::Vector { size, scalar } => (scalar, Some(size as u8)),
+        _ => return None
+    };
+
+    let kind = match scalar.kind {
+        naga::ScalarKind::Float => ScalarKind::Float,
+        naga::ScalarKind::Uint => ScalarKind::Uint,
+        naga::ScalarKind::Sint => ScalarKind::Sint,
+        _ => return None
+    };
+
+    Some((kind, scalar.width, size))
+}
+
+/// Check every shader location of the entry point against the attributes of the structure.
+///
+/// Returns the tokens making the crate rebuild when the shader file change.
+#[cfg(feature = "shader")]
+pub fn check_shader(
+    shader: &syn::LitStr,
+    entry: Option<&syn::LitStr>,
+    input: Option<&syn::LitStr>,
+    attributes: &[ShaderAttribute],
+    errors: &mut Vec<syn::Error>
+) -> proc_macro2::TokenStream {
+    let Shader { module, rebuild } = match load_shader(shader) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            errors.push(e);
+            return proc_macro2::TokenStream::new()
+        }
+    };
+
+    let (entry_point, inputs) = match entry_inputs(&module, shader, entry, input) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            errors.push(e);
+            return rebuild
+        }
+    };
+
+    let span = input.or(entry).unwrap_or(shader).span();
+
+    for input in &inputs {
+        let kind = input_scalar(&module, input.ty).map(|(kind, _, _)| kind);
+
+        let Some(attribute) = attributes.iter().find(|a| a.location == input.location) else {
+            errors.push(syn::Error::new(
+                span,
+                format!("Shader input `{}` at location {} of `{}` has no vertex attribute", input.name, input.location, entry_point.name)
+            ));
+            continue
+        };
+
+        if kind != Some(attribute.format.scalar_kind()) {
+            errors.push(syn::Error::new(
+                attribute.field.span(),
+                format!(
+                    "`{}` has the {} format {} but the shader input `{}` at location {} is {}",
+                    attribute.field,
+                    attribute.format.scalar_kind().name(),
+                    attribute.format.name(),
+                    input.name,
+                    input.location,
+                    kind.map_or("not a number", |kind| kind.name())
+                )
+            ));
+        }
+    }
+
+    for attribute in attributes {
+        if !inputs.iter().any(|input| input.location == attribute.location) {
+            errors.push(syn::Error::new(
+                attribute.field.span(),
+                format!("Shader location {} of `{}` is not read by `{}`", attribute.location, attribute.field, entry_point.name)
+            ));
+        }
+    }
+
+    rebuild
+}
+
+/// `vertex_from_wgsl!("shader.wgsl", "vs_main", #[derive(Clone, Copy)] pub Vertex, "vertex")` arguments,
+/// the attributes and visibility of the structure and the entry point argument being optional.
+/// `#[wrld(...)]` attributes are given to the Desc derive, the fields have the visibility of the structure.
+#[cfg(feature = "shader")]
+pub struct VertexFromWgsl {
+    shader: syn::LitStr,
+    entry: syn::LitStr,
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ident: syn::Ident,
+    input: Option<syn::LitStr>
+}
+
+#[cfg(feature = "shader")]
+impl syn::parse::Parse for VertexFromWgsl {
+    fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
+        let shader = tokens.parse()?;
+        tokens.parse::<syn::Token![,]>()?;
+        let entry = tokens.parse()?;
+        tokens.parse::<syn::Token![,]>()?;
+        let attrs = tokens.call(syn::Attribute::parse_outer)?;
+        let vis = tokens.parse()?;
+        let ident = tokens.parse()?;
+
+        let mut input = None;
+
+        if tokens.peek(syn::Token![,]) {
+            tokens.parse::<syn::Token![,]>()?;
+
+            if !tokens.is_empty() {
+                input = Some(tokens.parse()?);
+                tokens.parse::<Option<syn::Token![,]>>()?;
+            }
+        }
+
+        Ok(VertexFromWgsl { shader, entry, attrs, vis, ident, input })
+    }
+}
+
+/// Generate a `#[repr(C)]` structure deriving `wrld::Desc` with one field per location input of the entry point.
+#[cfg(feature = "shader")]
+pub fn expand_vertex_from_wgsl(input: VertexFromWgsl) -> syn::Result<proc_macro2::TokenStream> {
+    let VertexFromWgsl { shader, entry, attrs, vis, ident, input } = input;
+    let Shader { module, rebuild } = load_shader(&shader)?;
+    let (entry_point, inputs) = entry_inputs(&module, &shader, Some(&entry), input.as_ref())?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let mut fields: Vec<proc_macro2::TokenStream> = Vec::new();
+
+    for input in &inputs {
+        let converted = input_scalar(&module, input.ty)
+            .and_then(|(kind, width, size)| crate::converter::wgsl_type_name(kind, width, size));
+
+        let Some((attribute, rust_type)) = converted else {
+            errors.push(syn::Error::new(
+                entry.span(),
+                format!(
+                    "Shader input `{}` at location {} of `{}` has no matching vertex format",
+                    input.name, input.location, entry_point.name
+                )
+            ));
+            continue
+        };
+
+        let name = syn::parse_str::<syn::Ident>(&input.name)
+            .unwrap_or_else(|_| proc_macro2::Ident::new_raw(&input.name, proc_macro2::Span::call_site()));
+        let attribute = quote::format_ident!("{}", attribute);
+        let rust_type: syn::Type = syn::parse_str(&rust_type)?;
+        let location = input.location;
+
+        fields.push(quote::quote! {
+            #[#attribute(#location)] #vis #name: #rust_type
+        });
+    }
+
+    crate::macros::combine_errors(errors)?;
+
+    // Derive helper attributes have to follow the derive.
+    let (wrld_attrs, attrs): (Vec<syn::Attribute>, Vec<syn::Attribute>) = attrs.into_iter().partition(|attr| attr.path.is_ident("wrld"));
+
+    Ok(quote::quote! {
+        #rebuild
+
+        #(#attrs)*
+        #[repr(C)]
+        #[derive(::wrld::Desc)]
+        #(#wrld_attrs)*
+        #vis struct #ident {
+            #(#fields),*
+        }
+    })
+}
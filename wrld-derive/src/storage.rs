@@ -0,0 +1,263 @@
+use crate::macros::{combine_errors, get_entity, get_named_fields, get_shader_fields, get_wrld_attrs};
+use crate::memory::{round_up, AddressSpace, ShaderType};
+use crate::parser::private_path;
+
+pub fn derive_wrld_storage(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_storage(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Element type of a runtime-sized array field, `Vec<T>` or `&[T]`.
+fn runtime_array_element(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Reference(reference) => match reference.elem.as_ref() {
+            syn::Type::Slice(slice) => Some(&slice.elem),
+            _ => None
+        },
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) if segment.ident == "Vec" && args.args.len() == 1 => match &args.args[0] {
+                    syn::GenericArgument::Type(element) => Some(element),
+                    _ => None
+                },
+                _ => None
+            }
+        },
+        _ => None
+    }
+}
+
+fn expand_wrld_storage(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, generics, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::Storage")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+
+    if let Some(param) = generics.type_params().next() {
+        errors.push(syn::Error::new(param.ident.span(), "wrld::Storage does not support generic types, only lifetimes"));
+    }
+
+    if let Some(param) = generics.const_params().next() {
+        errors.push(syn::Error::new(param.ident.span(), "wrld::Storage does not support const generics, only lifetimes"));
+    }
+
+    let wrld = get_wrld_attrs(&attrs, "Storage", &["wgpu", "bytemuck"], &mut errors);
+    let entity = get_entity(fields, &mut errors);
+
+    // The runtime-sized array is laid out after the members, its element type is the shader type.
+    let (fields, runtime_field) = match entity.fields.split_last() {
+        Some((last, fields)) if runtime_array_element(&last.ty).is_some() => (fields, Some(last)),
+        _ => (entity.fields.as_slice(), None)
+    };
+
+    for field in fields.iter().filter(|field| runtime_array_element(&field.ty).is_some()) {
+        errors.push(syn::Error::new_spanned(&field.ty, "The runtime-sized array need to be the last field of the structure"));
+    }
+
+    let members = get_shader_fields(
+        fields.iter().filter(|field| runtime_array_element(&field.ty).is_none()),
+        AddressSpace::Storage,
+        &mut errors
+    );
+
+    let runtime_array: Option<(proc_macro2::Ident, ShaderType)> = runtime_field.and_then(|field| {
+        let element = runtime_array_element(&field.ty)?;
+
+        match ShaderType::from_type(element, AddressSpace::Storage) {
+            Ok(element) => Some((field.name.clone(), element)),
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        }
+    });
+
+    combine_errors(errors)?;
+
+    let private = private_path();
+    let wgpu = wrld.wgpu_path();
+    let bytemuck = wrld.bytemuck_path();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The layout does not depend on the lifetimes, the compile time checks use the structure with 'static lifetimes.
+    let static_lifetimes = generics.lifetimes().map(|_| quote::quote! { 'static });
+    let static_ident = if generics.lifetimes().next().is_some() {
+        quote::quote! { #ident<#(#static_lifetimes),*> }
+    } else {
+        quote::quote! { #ident }
+    };
+
+    let mut layout_members: Vec<proc_macro2::TokenStream> = members.iter().map(|(_, ty)| {
+        let align = ty.align_tokens(&private);
+        let size = ty.size_tokens(&private);
+
+        quote::quote! { (#align, #size) }
+    }).collect();
+
+    if let Some((_, element)) = &runtime_array {
+        let align = element.align_tokens(&private);
+        layout_members.push(quote::quote! { (#align, 0) });
+    }
+
+    let member_count = layout_members.len();
+
+    let mut checks: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut wgsl = format!("struct {} {{\n", ident);
+
+    // std430 offsets computed here, they are known until the first nested structure.
+    let mut expected_end = Some(0u64);
+
+    for (i, (name, ty)) in members.iter().chain(runtime_array.iter()).enumerate() {
+        let align = ty.align_tokens(&private);
+        let field = name.to_string();
+        let field = field.trim_start_matches("r#");
+        let message = format!("`{}` of `{}` is not at its WGSL std430 offset", field, ident);
+
+        let expected = expected_end.filter(|_| !ty.has_struct()).map(|end| round_up(ty.align(), end));
+        expected_end = expected.map(|offset| if i < members.len() { offset + ty.size() } else { offset });
+
+        checks.push(match (expected, i) {
+            (Some(expected), _) => quote::quote! {
+                ::core::assert!(offsets[#i] == #expected, #message);
+            },
+            (None, 0) => quote::quote! {
+                ::core::assert!((#align).is_power_of_two() && offsets[0] == 0, #message);
+            },
+            // After a nested structure, the member is aligned right after the end of the previous one.
+            (None, _) => {
+                let previous = i - 1;
+                let previous_size = members[previous].1.size_tokens(&private);
+
+                quote::quote! {
+                    ::core::assert!(
+                        (#align).is_power_of_two()
+                            && offsets[#i] % (#align) == 0
+                            && offsets[#i] >= offsets[#previous] + (#previous_size)
+                            && offsets[#i] - (offsets[#previous] + (#previous_size)) < (#align),
+                        #message
+                    );
+                }
+            }
+        });
+
+        if i < members.len() {
+            wgsl.push_str(&format!("    {}: {},\n", field, ty.wgsl_type()));
+        } else {
+            wgsl.push_str(&format!("    {}: array<{}>,\n", field, ty.wgsl_type()));
+        }
+    }
+
+    wgsl.push_str("}\n");
+
+    let size_check = match expected_end {
+        Some(end) => {
+            let align = members.iter().chain(runtime_array.iter()).map(|(_, ty)| ty.align()).max().unwrap_or(1);
+            let size = round_up(align, end);
+
+            quote::quote! {
+                ::core::assert!(align == #align && size == #size, "wrld::Storage structure size is not its WGSL std430 size");
+            }
+        },
+        None => quote::quote! {
+            ::core::assert!(size % align == 0, "wrld::Storage structure size is not a multiple of its alignment");
+        }
+    };
+
+    let writes: Vec<proc_macro2::TokenStream> = members.iter().enumerate().map(|(i, (name, ty))| {
+        ty.write_tokens(&private, &bytemuck, quote::quote! { self.#name }, quote::quote! { offsets[#i] }, 0)
+    }).collect();
+
+    let layout = quote::quote! { Self::WRLD_STORAGE_LAYOUT };
+
+    let (runtime_items, storage_impl) = match &runtime_array {
+        Some((name, element)) => {
+            let last = member_count - 1;
+            let stride = element.stride_tokens(&private);
+            let write = element.write_tokens(
+                &private, &bytemuck, quote::quote! { (*element) }, quote::quote! { (offsets[#last] + index as u64 * #stride) }, 1
+            );
+
+            let runtime_items = quote::quote! {
+                /// Size in bytes of the structure with `len` elements in its runtime-sized array,
+                /// rounded up to the alignment of the structure.
+                pub const fn storage_size(len: usize) -> u64 {
+                    #private::round_up(#layout.1, #layout.0[#last] + len as u64 * #stride)
+                }
+
+                /// Size of the storage buffer binding with one element in the runtime-sized array,
+                /// to use as `min_binding_size` of the bind group layout entry.
+                pub const MIN_BINDING_SIZE: ::core::option::Option<#wgpu::BufferSize> = #wgpu::BufferSize::new(Self::storage_size(1));
+
+                /// Write the structure and the elements of its runtime-sized array following the storage layout.
+                pub fn to_storage_bytes(&self) -> Vec<u8> {
+                    let offsets = #layout.0;
+                    let mut dst = vec![0u8; Self::storage_size(self.#name.len()) as usize];
+
+                    #(#writes)*
+
+                    for (index, element) in self.#name.iter().enumerate() {
+                        #write
+                    }
+
+                    dst
+                }
+            };
+
+            (runtime_items, proc_macro2::TokenStream::new())
+        },
+        None => {
+            let runtime_items = quote::quote! {
+                /// Size of the storage buffer binding, to use as `min_binding_size` of the bind group layout entry.
+                pub const MIN_BINDING_SIZE: ::core::option::Option<#wgpu::BufferSize> = #wgpu::BufferSize::new(#layout.2);
+
+                /// Write the structure following the storage layout.
+                pub fn to_storage_bytes(&self) -> Vec<u8> {
+                    let mut dst = vec![0u8; #layout.2 as usize];
+                    #private::Storage::write_storage(self, &mut dst);
+                    dst
+                }
+            };
+
+            let storage_impl = quote::quote! {
+                impl #impl_generics #private::Storage for #ident #ty_generics #where_clause {
+                    const ALIGN: u64 = #layout.1;
+                    const SIZE: u64 = #layout.2;
+
+                    fn write_storage(&self, dst: &mut [u8]) {
+                        let offsets = #layout.0;
+
+                        #(#writes)*
+                    }
+                }
+            };
+
+            (runtime_items, storage_impl)
+        }
+    };
+
+    Ok(quote::quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Offsets of the members, alignment and size of the structure.
+            #[doc(hidden)]
+            pub const WRLD_STORAGE_LAYOUT: ([u64; #member_count], u64, u64) = #private::struct_layout([#(#layout_members),*]);
+
+            /// WGSL structure matching the storage layout.
+            pub const WGSL: &'static str = #wgsl;
+
+            #runtime_items
+        }
+
+        #storage_impl
+
+        const _: () = {
+            let (offsets, align, size) = <#static_ident>::WRLD_STORAGE_LAYOUT;
+
+            #(#checks)*
+            #size_check
+        };
+    })
+}
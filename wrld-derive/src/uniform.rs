@@ -0,0 +1,77 @@
+use crate::macros::{combine_errors, get_entity, get_named_fields, get_shader_fields, get_wrld_attrs};
+use crate::memory::{AddressSpace, StructLayout};
+
+pub fn derive_wrld_uniform(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_uniform(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_wrld_uniform(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, vis, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::Uniform")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let wrld = get_wrld_attrs(&attrs, "Uniform", &["wgpu", "bytemuck"], &mut errors);
+    let entity = get_entity(fields, &mut errors);
+    let layout = StructLayout::new(get_shader_fields(&entity.fields, AddressSpace::Uniform, &mut errors), AddressSpace::Uniform);
+
+    combine_errors(errors)?;
+
+    let wgpu = wrld.wgpu_path();
+    let bytemuck = wrld.bytemuck_path();
+    let bytemuck_path = bytemuck.to_string().replace(' ', "");
+    let subclass_name = quote::format_ident!("{}{}", ident, "Uniform");
+
+    let padded_fields = layout.padded_fields(|name| {
+        entity.fields.iter().find(|f| &f.name == name).map(|f| f.vis.clone()).unwrap_or(syn::Visibility::Inherited)
+    });
+
+    let into_fields: Vec<proc_macro2::TokenStream> = layout.members.iter().map(|member| {
+        let name = &member.name;
+        let value = member.ty.pad(quote::quote! { other_data_from_ident_to_uniform.#name });
+
+        quote::quote! { #name: #value }
+    }).collect();
+
+    let wgsl = layout.wgsl_struct(&ident);
+    let size = layout.size;
+
+    Ok(quote::quote! {
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, #bytemuck::Pod, #bytemuck::Zeroable)]
+        #[bytemuck(crate = #bytemuck_path)]
+        #vis struct #subclass_name {
+            #(#padded_fields),*
+        }
+
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#subclass_name>() as u64 == #size,
+            "wrld::Uniform padded structure does not match the uniform layout"
+        );
+
+        impl From<&#ident> for #subclass_name {
+            fn from(other_data_from_ident_to_uniform: &#ident) -> Self {
+                Self {
+                    #(#into_fields,)*
+                    ..#bytemuck::Zeroable::zeroed()
+                }
+            }
+        }
+
+        impl From<#ident> for #subclass_name {
+            fn from(other_data_from_ident_to_uniform: #ident) -> Self {
+                Self::from(&other_data_from_ident_to_uniform)
+            }
+        }
+
+        impl #ident {
+            /// WGSL structure matching the uniform layout.
+            pub const WGSL: &'static str = #wgsl;
+
+            /// Size of the uniform buffer binding, to use as `min_binding_size` of the bind group layout entry.
+            pub const MIN_BINDING_SIZE: ::core::option::Option<#wgpu::BufferSize> = #wgpu::BufferSize::new(#size);
+        }
+    })
+}
@@ -0,0 +1,219 @@
+use crate::macros::{combine_errors, get_named_fields, get_wrld_attrs};
+use crate::memory::{Scalar, ShaderType};
+use crate::parser::parse_attrs;
+
+pub fn derive_wrld_varyings(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    expand_wrld_varyings(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+const INTERPOLATIONS: [&str; 3] = ["flat", "perspective", "linear"];
+
+/// Where a varying is written in the shader interface.
+enum Slot {
+    Location(u32),
+    Builtin(syn::Ident)
+}
+
+/// Varying of a field, with its WGSL `@interpolate(...)` arguments.
+struct Varying {
+    field: proc_macro2::Ident,
+    slot: Slot,
+    interpolation: Option<String>,
+    ty: ShaderType
+}
+
+/// `@interpolate` arguments of `#[flat]`, `#[perspective(centroid)]` or `#[linear(sample)]`.
+fn interpolation(kind: &str, attribute: &syn::Attribute) -> syn::Result<String> {
+    if attribute.tokens.is_empty() {
+        return Ok(kind.to_string())
+    }
+
+    let sampling : syn::Ident = attribute.parse_args()?;
+
+    let (names, expected) = match kind {
+        "flat" => ("first or either", ["first", "either"].as_slice()),
+        _ => ("center, centroid or sample", ["center", "centroid", "sample"].as_slice())
+    };
+
+    if !expected.iter().any(|e| sampling == e) {
+        return Err(syn::Error::new(
+            sampling.span(),
+            format!("Unknown sampling `{}` for #[{}], expected {}", sampling, kind, names)
+        ));
+    }
+
+    Ok(format!("{}, {}", kind, sampling))
+}
+
+/// Scalar or vector type of a varying.
+fn varying_type(ty: &syn::Type) -> syn::Result<ShaderType> {
+    match ShaderType::parse(ty) {
+        Some(shader_type @ (ShaderType::Scalar(_) | ShaderType::Vector(_, _))) => Ok(shader_type),
+        _ => Err(syn::Error::new_spanned(ty, "This type cannot be a varying, expected f32, u32, i32 or vectors of them ([T; 2..=4])"))
+    }
+}
+
+fn expand_wrld_varyings(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::DeriveInput {ident, data, attrs, generics, ..} = input;
+    let fields = get_named_fields(&ident, &data, "wrld::Varyings")?;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let wrld = get_wrld_attrs(&attrs, "Varyings", &["auto_location"], &mut errors);
+    let mut next_location = wrld.auto_location.unwrap_or(0);
+    let mut varyings: Vec<Varying> = Vec::new();
+
+    for field in fields {
+        let Some(name) = field.ident.clone() else { continue };
+        let mut location: Option<syn::Attribute> = None;
+        let mut builtin: Option<syn::Attribute> = None;
+        let mut interpolations: Vec<(String, syn::Attribute)> = Vec::new();
+
+        parse_attrs(&field.attrs, Box::new(|attr| {
+            let kind = attr.segment.ident.to_string();
+
+            if kind == "location" {
+                location = Some(attr.attribute.clone());
+            } else if kind == "builtin" {
+                builtin = Some(attr.attribute.clone());
+            } else if INTERPOLATIONS.contains(&kind.as_str()) {
+                interpolations.push((kind, attr.attribute.clone()));
+            }
+        }));
+
+        if interpolations.len() > 1 {
+            errors.push(syn::Error::new_spanned(&interpolations[1].1, "A varying can only have one interpolation"));
+        }
+
+        let ty = match varying_type(&field.ty) {
+            Ok(ty) => ty,
+            Err(e) => {
+                errors.push(e);
+                continue
+            }
+        };
+
+        if let Some(builtin) = builtin {
+            let slot = builtin.parse_args::<syn::Ident>().and_then(|builtin| {
+                if builtin != "position" {
+                    return Err(syn::Error::new(
+                        builtin.span(),
+                        format!("Unknown builtin `{}`, only `position` is shared by the vertex output and the fragment input", builtin)
+                    ));
+                }
+
+                if ty != ShaderType::Vector(Scalar::F32, 4) {
+                    return Err(syn::Error::new_spanned(&field.ty, "#[builtin(position)] must be a [f32; 4]"));
+                }
+
+                Ok(Slot::Builtin(builtin))
+            });
+
+            for attribute in location.iter().chain(interpolations.iter().map(|(_, attribute)| attribute)) {
+                errors.push(syn::Error::new_spanned(attribute, "A builtin cannot have a location or an interpolation"));
+            }
+
+            match slot {
+                Ok(slot) => {
+                    if let Some(other) = varyings.iter().find(|v| matches!(&v.slot, Slot::Builtin(_))) {
+                        errors.push(syn::Error::new_spanned(
+                            &builtin,
+                            format!("#[builtin(position)] is already used by `{}`", other.field)
+                        ));
+                    }
+
+                    varyings.push(Varying { field: name, slot, interpolation: None, ty })
+                },
+                Err(e) => errors.push(e)
+            }
+
+            continue
+        }
+
+        if let Some(attribute) = &location {
+            match attribute.parse_args::<syn::LitInt>().and_then(|lit| lit.base10_parse::<u32>()) {
+                Ok(location) => next_location = location,
+                Err(e) => errors.push(syn::Error::new(e.span(), "Only integer is authorize for shader location data"))
+            }
+        }
+
+        let interpolation = match interpolations.first() {
+            Some((kind, attribute)) => match interpolation(kind, attribute) {
+                Ok(interpolation) => Some(interpolation),
+                Err(e) => {
+                    errors.push(e);
+                    continue
+                }
+            },
+            None => None
+        };
+
+        let is_integer = matches!(ty, ShaderType::Scalar(scalar) | ShaderType::Vector(scalar, _) if scalar != Scalar::F32);
+        let is_flat = interpolations.first().is_some_and(|(kind, _)| kind == "flat");
+
+        if is_integer && !is_flat {
+            errors.push(syn::Error::new_spanned(
+                &field.ty,
+                format!("`{}` is a `{}` varying, integer varyings must be #[flat]", name, ty.wgsl_type())
+            ));
+        }
+
+        if let Some(other) = varyings.iter().find(|v| matches!(v.slot, Slot::Location(l) if l == next_location)) {
+            errors.push(syn::Error::new(
+                name.span(),
+                format!("Shader location {} is already used by `{}`", next_location, other.field)
+            ));
+        }
+
+        varyings.push(Varying { field: name, slot: Slot::Location(next_location), interpolation, ty });
+        next_location += 1;
+    }
+
+    combine_errors(errors)?;
+
+    let mut wgsl = format!("struct {} {{\n", ident);
+
+    for varying in &varyings {
+        let name = varying.field.to_string();
+        let name = name.trim_start_matches("r#");
+
+        let mut attributes = match &varying.slot {
+            Slot::Location(location) => format!("@location({})", location),
+            Slot::Builtin(builtin) => format!("@builtin({})", builtin)
+        };
+
+        if let Some(interpolation) = &varying.interpolation {
+            attributes.push_str(&format!(" @interpolate({})", interpolation));
+        }
+
+        wgsl.push_str(&format!("    {} {}: {},\n", attributes, name, varying.ty.wgsl_type()));
+    }
+
+    wgsl.push_str("}\n");
+
+    let location_consts = varyings.iter().filter_map(|varying| {
+        let Slot::Location(location) = varying.slot else { return None };
+
+        let const_name = quote::format_ident!(
+            "{}_LOCATION",
+            varying.field.to_string().trim_start_matches("r#").to_ascii_uppercase()
+        );
+
+        Some(quote::quote! {
+            pub const #const_name: u32 = #location;
+        })
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote::quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// WGSL structure of the vertex output and fragment input.
+            pub const WGSL: &'static str = #wgsl;
+
+            #(#location_consts)*
+        }
+    })
+}